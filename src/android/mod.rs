@@ -2,13 +2,17 @@
 mod sysinfo_ffi;
 mod system_properties;
 
+#[cfg(feature = "full")]
 use crate::extra;
 use crate::shared;
 use crate::traits::*;
 use itertools::Itertools;
 use std::ffi::{CStr, CString};
 use std::fs;
-use std::path::{Path, PathBuf};
+#[cfg(feature = "full")]
+use std::path::Path;
+use std::path::PathBuf;
+#[cfg(feature = "full")]
 use std::process::{Command, Stdio};
 use sysinfo_ffi::sysinfo;
 use system_properties::getprop;
@@ -24,6 +28,7 @@ impl From<std::num::ParseFloatError> for ReadoutError {
     }
 }
 
+#[cfg(feature = "full")]
 pub struct AndroidBatteryReadout;
 
 pub struct AndroidKernelReadout {
@@ -39,9 +44,13 @@ pub struct AndroidMemoryReadout {
 }
 
 pub struct AndroidProductReadout;
+#[cfg(feature = "full")]
 pub struct AndroidPackageReadout;
+#[cfg(feature = "full")]
 pub struct AndroidNetworkReadout;
+pub struct AndroidProcessReadout;
 
+#[cfg(feature = "full")]
 impl BatteryReadout for AndroidBatteryReadout {
     fn new() -> Self {
         AndroidBatteryReadout
@@ -70,7 +79,8 @@ impl BatteryReadout for AndroidBatteryReadout {
         let status_text = extra::pop_newline(fs::read_to_string(bat_path)?).to_lowercase();
         match &status_text[..] {
             "charging" => Ok(BatteryState::Charging),
-            "discharging" | "full" => Ok(BatteryState::Discharging),
+            "discharging" => Ok(BatteryState::Discharging),
+            "full" => Ok(BatteryState::Full),
             s => Err(ReadoutError::Other(format!(
                 "Got unexpected value '{}' from {}.",
                 s,
@@ -119,6 +129,10 @@ impl KernelReadout for AndroidKernelReadout {
             Err(ReadoutError::Other(String::from("Failed to get os_type")))
         }
     }
+
+    fn kernel_parameters(&self) -> Result<String, ReadoutError> {
+        shared::kernel_parameters()
+    }
 }
 
 impl GeneralReadout for AndroidGeneralReadout {
@@ -187,6 +201,10 @@ impl GeneralReadout for AndroidGeneralReadout {
         Err(ReadoutError::NotImplemented)
     }
 
+    fn terminal_size(&self) -> Result<(u16, u16), ReadoutError> {
+        shared::terminal_size()
+    }
+
     fn shell(&self, format: ShellFormat, kind: ShellKind) -> Result<String, ReadoutError> {
         if let Some(shell) = std::env::var_os("SHELL") {
             if let Some(relative) = PathBuf::from(shell).file_name() {
@@ -358,6 +376,32 @@ impl MemoryReadout for AndroidMemoryReadout {
 
         Ok(total - free - cached - reclaimable - buffers)
     }
+
+    fn swap_total(&self) -> Result<u64, ReadoutError> {
+        let mut info = self.sysinfo;
+        let info_ptr: *mut sysinfo = &mut info;
+        let ret = unsafe { sysinfo(info_ptr) };
+        if ret != -1 {
+            Ok(info.totalswap * info.mem_unit as u64 / 1024)
+        } else {
+            Err(ReadoutError::Other(
+                "Failed to get system statistics".to_string(),
+            ))
+        }
+    }
+
+    fn swap_used(&self) -> Result<u64, ReadoutError> {
+        let mut info = self.sysinfo;
+        let info_ptr: *mut sysinfo = &mut info;
+        let ret = unsafe { sysinfo(info_ptr) };
+        if ret != -1 {
+            Ok((info.totalswap - info.freeswap) * info.mem_unit as u64 / 1024)
+        } else {
+            Err(ReadoutError::Other(
+                "Failed to get system statistics".to_string(),
+            ))
+        }
+    }
 }
 
 impl ProductReadout for AndroidProductReadout {
@@ -381,6 +425,7 @@ impl ProductReadout for AndroidProductReadout {
     }
 }
 
+#[cfg(feature = "full")]
 impl PackageReadout for AndroidPackageReadout {
     fn new() -> Self {
         AndroidPackageReadout
@@ -410,6 +455,7 @@ impl PackageReadout for AndroidPackageReadout {
     }
 }
 
+#[cfg(feature = "full")]
 impl AndroidPackageReadout {
     /// Returns the number of installed apps for the system
     /// Includes all apps ( user + system )
@@ -451,6 +497,7 @@ impl AndroidPackageReadout {
     }
 }
 
+#[cfg(feature = "full")]
 impl NetworkReadout for AndroidNetworkReadout {
     fn new() -> Self {
         AndroidNetworkReadout
@@ -476,7 +523,21 @@ impl NetworkReadout for AndroidNetworkReadout {
         shared::logical_address(interface)
     }
 
+    fn broadcast_address(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
+        shared::broadcast_address(interface)
+    }
+
+    fn subnet_mask(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
+        shared::subnet_mask(interface)
+    }
+
     fn physical_address(&self, _: Option<&str>) -> Result<String, ReadoutError> {
         Err(ReadoutError::NotImplemented)
     }
 }
+
+impl ProcessReadout for AndroidProcessReadout {
+    fn new() -> Self {
+        AndroidProcessReadout
+    }
+}
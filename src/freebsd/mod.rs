@@ -4,15 +4,19 @@ use crate::shared;
 use crate::traits::*;
 use crate::winman;
 use std::fs;
-use std::path::{Path, PathBuf};
+#[cfg(feature = "full")]
+use std::path::Path;
+use std::path::PathBuf;
 use sysctl::{Ctl, Sysctl};
 
+#[cfg(feature = "full")]
 impl From<sqlite::Error> for ReadoutError {
     fn from(e: sqlite::Error) -> Self {
         ReadoutError::Other(e.to_string())
     }
 }
 
+#[cfg(feature = "full")]
 pub struct FreeBSDBatteryReadout {
     battery_state_ctl: Option<Ctl>,
     battery_life_ctl: Option<Ctl>,
@@ -31,14 +35,19 @@ pub struct FreeBSDGeneralReadout {
 pub struct FreeBSDMemoryReadout {
     // available memory
     physmem_ctl: Option<Ctl>,
-    // used memory
-    usermem_ctl: Option<Ctl>,
+    // free memory, in pages
+    free_count_ctl: Option<Ctl>,
+    page_size_ctl: Option<Ctl>,
 }
 
 pub struct FreeBSDProductReadout;
+#[cfg(feature = "full")]
 pub struct FreeBSDPackageReadout;
+#[cfg(feature = "full")]
 pub struct FreeBSDNetworkReadout;
+pub struct FreeBSDProcessReadout;
 
+#[cfg(feature = "full")]
 impl BatteryReadout for FreeBSDBatteryReadout {
     fn new() -> Self {
         FreeBSDBatteryReadout {
@@ -138,6 +147,10 @@ impl GeneralReadout for FreeBSDGeneralReadout {
         shared::username()
     }
 
+    fn logged_in_user_names(&self) -> Result<Vec<String>, ReadoutError> {
+        shared::logged_in_user_names()
+    }
+
     fn hostname(&self) -> Result<String, ReadoutError> {
         self.hostname_ctl
             .as_ref()
@@ -229,6 +242,10 @@ impl GeneralReadout for FreeBSDGeneralReadout {
         Ok(terminal)
     }
 
+    fn terminal_size(&self) -> Result<(u16, u16), ReadoutError> {
+        shared::terminal_size()
+    }
+
     fn shell(&self, shorthand: ShellFormat, kind: ShellKind) -> Result<String, ReadoutError> {
         shared::shell(shorthand, kind)
     }
@@ -272,7 +289,10 @@ impl GeneralReadout for FreeBSDGeneralReadout {
         };
 
         match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
-            Ok(unix_epoch) => Ok(unix_epoch.as_secs() as usize - boot_time.tv_sec as usize),
+            // The clock may have been set back since boot.
+            Ok(unix_epoch) => (unix_epoch.as_secs() as usize)
+                .checked_sub(boot_time.tv_sec as usize)
+                .ok_or(ReadoutError::MetricNotAvailable),
             Err(_) => Err(ReadoutError::MetricNotAvailable),
         }
     }
@@ -303,7 +323,8 @@ impl MemoryReadout for FreeBSDMemoryReadout {
     fn new() -> Self {
         FreeBSDMemoryReadout {
             physmem_ctl: Ctl::new("hw.physmem").ok(),
-            usermem_ctl: Ctl::new("hw.usermem").ok(),
+            free_count_ctl: Ctl::new("vm.stats.vm.v_free_count").ok(),
+            page_size_ctl: Ctl::new("hw.pagesize").ok(),
         }
     }
 
@@ -320,15 +341,18 @@ impl MemoryReadout for FreeBSDMemoryReadout {
     }
 
     fn free(&self) -> Result<u64, ReadoutError> {
-        Ok(self
-            .usermem_ctl
-            .as_ref()
-            .ok_or(ReadoutError::MetricNotAvailable)?
-            .value_string()
-            .unwrap()
-            .parse::<u64>()
-            .unwrap()
-            / 1024)
+        // hw.usermem is the memory that isn't wired by the kernel, not the memory that's
+        // free, which the VM statistics count in pages.
+        let pages = |ctl: &Option<Ctl>| -> Result<u64, ReadoutError> {
+            ctl.as_ref()
+                .ok_or(ReadoutError::MetricNotAvailable)?
+                .value_string()
+                .map_err(|e| ReadoutError::Other(e.to_string()))?
+                .parse::<u64>()
+                .map_err(|e| ReadoutError::Other(e.to_string()))
+        };
+
+        Ok(pages(&self.free_count_ctl)? * pages(&self.page_size_ctl)? / 1024)
     }
 
     fn buffers(&self) -> Result<u64, ReadoutError> {
@@ -344,10 +368,40 @@ impl MemoryReadout for FreeBSDMemoryReadout {
     }
 
     fn used(&self) -> Result<u64, ReadoutError> {
-        let total = self.total().unwrap();
-        let free = self.free().unwrap();
+        let total = self.total()?;
+        let free = self.free()?;
+
+        Ok(total.saturating_sub(free))
+    }
+
+    fn swap_total(&self) -> Result<u64, ReadoutError> {
+        Ok(FreeBSDMemoryReadout::swap_usage()?.0)
+    }
+
+    fn swap_used(&self) -> Result<u64, ReadoutError> {
+        Ok(FreeBSDMemoryReadout::swap_usage()?.1)
+    }
+}
 
-        Ok(total - free)
+impl FreeBSDMemoryReadout {
+    /// Returns the total and used amount of swap space, in kilobytes.
+    fn swap_usage() -> Result<(u64, u64), ReadoutError> {
+        // The summary looks like "Total:  2097152  1024", and is left out entirely when
+        // no swap device is configured.
+        let output = std::process::Command::new("swapctl")
+            .args(["-s", "-k"])
+            .output()?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut fields = stdout.split_whitespace().skip(1).map(str::parse::<u64>);
+
+        match (fields.next(), fields.next()) {
+            (Some(Ok(total)), Some(Ok(used))) => Ok((total, used)),
+            _ if output.status.success() => Ok((0, 0)),
+            _ => Err(ReadoutError::Other(String::from(
+                "swapctl failed to summarize the swap devices.",
+            ))),
+        }
     }
 }
 
@@ -369,6 +423,7 @@ impl ProductReadout for FreeBSDProductReadout {
     }
 }
 
+#[cfg(feature = "full")]
 impl PackageReadout for FreeBSDPackageReadout {
     fn new() -> Self {
         FreeBSDPackageReadout
@@ -385,10 +440,19 @@ impl PackageReadout for FreeBSDPackageReadout {
             packages.push((PackageManager::Cargo, c));
         }
 
+        if let Some(c) = FreeBSDPackageReadout::count_pnpm() {
+            packages.push((PackageManager::Pnpm, c));
+        }
+
+        if let Some(c) = FreeBSDPackageReadout::count_bun() {
+            packages.push((PackageManager::Bun, c));
+        }
+
         packages
     }
 }
 
+#[cfg(feature = "full")]
 impl FreeBSDPackageReadout {
     fn count_pkg() -> Option<usize> {
         let db = "/var/db/pkg/local.sqlite";
@@ -415,8 +479,17 @@ impl FreeBSDPackageReadout {
     fn count_cargo() -> Option<usize> {
         shared::count_cargo()
     }
+
+    fn count_pnpm() -> Option<usize> {
+        shared::count_pnpm()
+    }
+
+    fn count_bun() -> Option<usize> {
+        shared::count_bun()
+    }
 }
 
+#[cfg(feature = "full")]
 impl NetworkReadout for FreeBSDNetworkReadout {
     fn new() -> Self {
         FreeBSDNetworkReadout
@@ -442,7 +515,21 @@ impl NetworkReadout for FreeBSDNetworkReadout {
         shared::logical_address(interface)
     }
 
+    fn broadcast_address(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
+        shared::broadcast_address(interface)
+    }
+
+    fn subnet_mask(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
+        shared::subnet_mask(interface)
+    }
+
     fn physical_address(&self, _: Option<&str>) -> Result<String, ReadoutError> {
         Err(ReadoutError::NotImplemented)
     }
 }
+
+impl ProcessReadout for FreeBSDProcessReadout {
+    fn new() -> Self {
+        FreeBSDProcessReadout
+    }
+}
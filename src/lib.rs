@@ -1,91 +1,137 @@
 use cfg_if::cfg_if;
+use std::collections::HashMap;
 
 cfg_if! {
     if #[cfg(all(target_os = "linux", feature = "openwrt"))] {
         mod extra;
         mod openwrt;
 
+        #[cfg(feature = "full")]
         pub type BatteryReadout = openwrt::OpenWrtBatteryReadout;
         pub type KernelReadout = openwrt::OpenWrtKernelReadout;
         pub type MemoryReadout = openwrt::OpenWrtMemoryReadout;
         pub type GeneralReadout = openwrt::OpenWrtGeneralReadout;
         pub type ProductReadout = openwrt::OpenWrtProductReadout;
+        #[cfg(feature = "full")]
         pub type PackageReadout = openwrt::OpenWrtPackageReadout;
+        #[cfg(feature = "full")]
         pub type NetworkReadout = openwrt::OpenWrtNetworkReadout;
+        pub type ProcessReadout = openwrt::OpenWrtProcessReadout;
     } else if #[cfg(all(target_os = "linux", not(feature = "openwrt")))] {
         mod extra;
         mod linux;
         mod winman;
 
+        #[cfg(feature = "full")]
         pub type BatteryReadout = linux::LinuxBatteryReadout;
         pub type KernelReadout = linux::LinuxKernelReadout;
         pub type MemoryReadout = linux::LinuxMemoryReadout;
         pub type GeneralReadout = linux::LinuxGeneralReadout;
         pub type ProductReadout = linux::LinuxProductReadout;
+        #[cfg(feature = "full")]
         pub type PackageReadout = linux::LinuxPackageReadout;
+        #[cfg(feature = "full")]
         pub type NetworkReadout = linux::LinuxNetworkReadout;
+        pub type ProcessReadout = linux::LinuxProcessReadout;
     } else if #[cfg(target_os = "macos")] {
         mod extra;
         mod macos;
 
+        #[cfg(feature = "full")]
         pub type BatteryReadout = macos::MacOSBatteryReadout;
         pub type KernelReadout = macos::MacOSKernelReadout;
         pub type MemoryReadout = macos::MacOSMemoryReadout;
         pub type GeneralReadout = macos::MacOSGeneralReadout;
         pub type ProductReadout = macos::MacOSProductReadout;
+        #[cfg(feature = "full")]
         pub type PackageReadout = macos::MacOSPackageReadout;
+        #[cfg(feature = "full")]
         pub type NetworkReadout = macos::MacOSNetworkReadout;
+        pub type ProcessReadout = macos::MacOSProcessReadout;
     } else if #[cfg(target_os = "netbsd")] {
         mod extra;
         mod netbsd;
         mod winman;
         pub mod dirs;
 
+        #[cfg(feature = "full")]
         pub type BatteryReadout = netbsd::NetBSDBatteryReadout;
         pub type KernelReadout = netbsd::NetBSDKernelReadout;
         pub type MemoryReadout = netbsd::NetBSDMemoryReadout;
         pub type GeneralReadout = netbsd::NetBSDGeneralReadout;
         pub type ProductReadout = netbsd::NetBSDProductReadout;
+        #[cfg(feature = "full")]
         pub type PackageReadout = netbsd::NetBSDPackageReadout;
+        #[cfg(feature = "full")]
         pub type NetworkReadout = netbsd::NetBSDNetworkReadout;
+        pub type ProcessReadout = netbsd::NetBSDProcessReadout;
     } else if #[cfg(target_os = "windows")] {
         mod windows;
 
+        #[cfg(feature = "full")]
         pub type BatteryReadout = windows::WindowsBatteryReadout;
         pub type KernelReadout = windows::WindowsKernelReadout;
         pub type MemoryReadout = windows::WindowsMemoryReadout;
         pub type GeneralReadout = windows::WindowsGeneralReadout;
         pub type ProductReadout = windows::WindowsProductReadout;
+        #[cfg(feature = "full")]
         pub type PackageReadout = windows::WindowsPackageReadout;
+        #[cfg(feature = "full")]
         pub type NetworkReadout = windows::WindowsNetworkReadout;
+        pub type ProcessReadout = windows::WindowsProcessReadout;
+
+        #[cfg(feature = "full")]
+        pub type WindowsWmi = windows::WindowsWmi;
+        #[cfg(feature = "full")]
+        pub type WmiVariant = wmi::Variant;
     } else if #[cfg(target_os = "android")] {
         mod android;
         mod extra;
 
+        #[cfg(feature = "full")]
         pub type BatteryReadout = android::AndroidBatteryReadout;
         pub type KernelReadout = android::AndroidKernelReadout;
         pub type MemoryReadout = android::AndroidMemoryReadout;
         pub type GeneralReadout = android::AndroidGeneralReadout;
         pub type ProductReadout = android::AndroidProductReadout;
+        #[cfg(feature = "full")]
         pub type PackageReadout = android::AndroidPackageReadout;
+        #[cfg(feature = "full")]
         pub type NetworkReadout = android::AndroidNetworkReadout;
+        pub type ProcessReadout = android::AndroidProcessReadout;
     } else if #[cfg(target_os = "freebsd")] {
         mod extra;
         mod freebsd;
         mod winman;
 
+        #[cfg(feature = "full")]
         pub type BatteryReadout = freebsd::FreeBSDBatteryReadout;
         pub type KernelReadout = freebsd::FreeBSDKernelReadout;
         pub type MemoryReadout = freebsd::FreeBSDMemoryReadout;
         pub type GeneralReadout = freebsd::FreeBSDGeneralReadout;
         pub type ProductReadout = freebsd::FreeBSDProductReadout;
+        #[cfg(feature = "full")]
         pub type PackageReadout = freebsd::FreeBSDPackageReadout;
+        #[cfg(feature = "full")]
         pub type NetworkReadout = freebsd::FreeBSDNetworkReadout;
+        pub type ProcessReadout = freebsd::FreeBSDProcessReadout;
     } else {
         compiler_error!("This platform is currently not supported by libmacchina.");
     }
 }
 
+// Builds without the `full` feature leave out the battery, network and package readouts, and
+// with them the dependencies they pull in.
+#[cfg(not(feature = "full"))]
+mod minimal;
+
+#[cfg(not(feature = "full"))]
+pub type BatteryReadout = minimal::MinimalBatteryReadout;
+#[cfg(not(feature = "full"))]
+pub type PackageReadout = minimal::MinimalPackageReadout;
+#[cfg(not(feature = "full"))]
+pub type NetworkReadout = minimal::MinimalNetworkReadout;
+
 pub struct Readouts {
     pub battery: BatteryReadout,
     pub kernel: KernelReadout,
@@ -94,6 +140,305 @@ pub struct Readouts {
     pub product: ProductReadout,
     pub packages: PackageReadout,
     pub network: NetworkReadout,
+    pub process: ProcessReadout,
+}
+
+/// Queries every readout available on the host and collects the values that could be read,
+/// keyed by the readout and method that produced them, _e.g._ `"kernel.os_release"`.
+///
+/// Readouts that fail or aren't implemented on the host are left out. Amounts of memory are
+/// given in kilobytes, disk space in bytes, uptime in seconds, and percentages and
+/// temperatures carry their respective units.
+pub fn read_all() -> HashMap<&'static str, String> {
+    use traits::*;
+
+    fn insert<T>(
+        values: &mut HashMap<&'static str, String>,
+        key: &'static str,
+        readout: Result<T, ReadoutError>,
+        format: impl FnOnce(T) -> String,
+    ) {
+        if let Ok(value) = readout {
+            values.insert(key, format(value));
+        }
+    }
+
+    let mut values = HashMap::new();
+
+    macro_rules! read {
+        ($key:literal, $readout:expr) => {
+            insert(&mut values, $key, $readout, |value| value.to_string())
+        };
+        ($key:literal, $readout:expr, $format:expr) => {
+            insert(&mut values, $key, $readout, $format)
+        };
+    }
+
+    let battery = crate::BatteryReadout::new();
+    read!("battery.percentage", battery.percentage(), |p| format!(
+        "{p}%"
+    ));
+    read!(
+        "battery.percentage_precise",
+        battery.percentage_precise(),
+        |p| format!("{p:.2}%")
+    );
+    read!("battery.status", battery.status());
+    read!("battery.health", battery.health(), |h| format!("{h}%"));
+    read!("battery.manufacturer", battery.manufacturer());
+    read!("battery.model", battery.model());
+    read!("battery.energy_now", battery.energy_now(), |e| format!(
+        "{e} µWh"
+    ));
+    read!("battery.energy_full", battery.energy_full(), |e| format!(
+        "{e} µWh"
+    ));
+
+    let kernel = crate::KernelReadout::new();
+    read!("kernel.os_release", kernel.os_release());
+    read!("kernel.os_type", kernel.os_type());
+    read!("kernel.pretty_kernel", kernel.pretty_kernel());
+    read!("kernel.kernel_parameters", kernel.kernel_parameters());
+
+    let memory = crate::MemoryReadout::new();
+    read!("memory.total", memory.total(), |m| format!("{m} kB"));
+    read!("memory.free", memory.free(), |m| format!("{m} kB"));
+    read!("memory.buffers", memory.buffers(), |m| format!("{m} kB"));
+    read!("memory.cached", memory.cached(), |m| format!("{m} kB"));
+    read!("memory.reclaimable", memory.reclaimable(), |m| format!(
+        "{m} kB"
+    ));
+    read!("memory.used", memory.used(), |m| format!("{m} kB"));
+    read!("memory.swap_total", memory.swap_total(), |m| format!(
+        "{m} kB"
+    ));
+    read!("memory.swap_used", memory.swap_used(), |m| format!(
+        "{m} kB"
+    ));
+    read!("memory.memory_limit", memory.memory_limit(), |m| format!(
+        "{m} kB"
+    ));
+    read!("memory.swap_limit", memory.swap_limit(), |m| format!(
+        "{m} kB"
+    ));
+    read!("memory.swap_io", memory.swap_io(), |io| {
+        format!("{} pages in / {} pages out", io.0, io.1)
+    });
+
+    let general = crate::GeneralReadout::new();
+    read!("general.backlight", general.backlight(), |b| format!(
+        "{b}%"
+    ));
+    read!("general.resolution", general.resolution());
+    read!("general.username", general.username());
+    read!("general.hostname", general.hostname());
+    read!("general.distribution", general.distribution());
+    read!("general.os_codename", general.os_codename());
+    read!("general.desktop_environment", general.desktop_environment());
+    read!("general.session", general.session());
+    read!("general.window_manager", general.window_manager());
+    read!("general.wayland_compositor", general.wayland_compositor());
+    read!(
+        "general.display_server_version",
+        general.display_server_version()
+    );
+    read!("general.terminal", general.terminal());
+    read!("general.system_language", general.system_language());
+    read!(
+        "general.hibernation_supported",
+        general.hibernation_supported()
+    );
+    read!("general.terminal_size", general.terminal_size(), |size| {
+        format!("{} columns x {} rows", size.0, size.1)
+    });
+    read!(
+        "general.shell",
+        general.shell(ShellFormat::Relative, ShellKind::Current)
+    );
+    read!("general.cpu_model_name", general.cpu_model_name());
+    read!("general.cpu_usage", general.cpu_usage(), |u| format!(
+        "{u}%"
+    ));
+    read!("general.cpu_physical_cores", general.cpu_physical_cores());
+    read!("general.cpu_cores", general.cpu_cores());
+    read!("general.cpu_hyper_threading", general.cpu_hyper_threading());
+    read!("general.cpu_topology", general.cpu_topology(), |t| format!(
+        "{} socket(s), {} core(s) per socket, {} thread(s) per core, {} thread(s)",
+        t.sockets, t.cores_per_socket, t.threads_per_core, t.total_threads
+    ));
+    read!(
+        "general.cpu_max_frequency",
+        general.cpu_max_frequency(),
+        |f| format!("{f} MHz")
+    );
+    read!("general.cpu_temperature", general.cpu_temperature(), |t| {
+        format!("{t:.1}°C")
+    });
+    read!("general.cpu_throttled", general.cpu_throttled());
+    read!("general.uptime", general.uptime(), |u| format!("{u} s"));
+    read!("general.machine", general.machine());
+    read!("general.os_name", general.os_name());
+    read!("general.wsl_version", general.wsl_version());
+    read!("general.bootloader", general.bootloader());
+    read!("general.disk_space", general.disk_space(), |space| {
+        format!("{} B / {} B", space.0, space.1)
+    });
+    read!(
+        "general.disk_model",
+        general.disk_model(std::path::Path::new("/"))
+    );
+    read!("general.disk_io", general.disk_io(), |io| {
+        format!("{} B read / {} B written", io.0, io.1)
+    });
+    read!("general.gpus", general.gpus(), |gpus| gpus.join(", "));
+    read!("general.monitor_names", general.monitor_names(), |names| {
+        names.join(", ")
+    });
+    read!(
+        "general.battery_charging_speed",
+        general.battery_charging_speed(),
+        |w| format!("{w:.1} W")
+    );
+    read!(
+        "general.connected_monitors_info",
+        general.connected_monitors_info(),
+        |monitors| monitors
+            .iter()
+            .map(|m| format!(
+                "{} {}x{} @ {} Hz",
+                m.name, m.resolution.0, m.resolution.1, m.refresh_rate
+            ))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    read!("general.color_depth", general.color_depth(), |d| format!(
+        "{d} bits"
+    ));
+    read!("general.screen_is_hdr", general.screen_is_hdr());
+    read!("general.display_technology", general.display_technology());
+    read!(
+        "general.installed_fonts_count",
+        general.installed_fonts_count()
+    );
+    read!("general.gpu_count", general.gpu_count());
+    read!("general.gpu_temp", general.gpu_temp(), |temps| {
+        temps
+            .iter()
+            .map(|(gpu, temp)| format!("{gpu}: {temp:.1}°C"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    });
+    read!(
+        "general.gpu_driver_name",
+        general.gpu_driver_name(),
+        |drivers| {
+            drivers
+                .iter()
+                .map(|(gpu, driver)| format!("{gpu}: {driver}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+    );
+    read!("general.gpu_vram", general.gpu_vram(), |vram| {
+        vram.iter()
+            .map(|(gpu, megabytes)| format!("{gpu}: {megabytes} MB"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    });
+    read!(
+        "general.thunderbolt_security_level",
+        general.thunderbolt_security_level()
+    );
+    read!("general.has_touchscreen", general.has_touchscreen());
+    read!("general.keyboard_layout", general.keyboard_layout());
+    read!("general.mouse_speed", general.mouse_speed());
+    read!(
+        "general.default_audio_output",
+        general.default_audio_output()
+    );
+    read!("general.entropy_available", general.entropy_available());
+    read!("general.raid_status", general.raid_status(), |arrays| {
+        arrays
+            .iter()
+            .map(|(device, state)| format!("{device}: {state}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    });
+
+    // OpenWrt doesn't provide any product information.
+    #[cfg(not(feature = "openwrt"))]
+    {
+        let product = crate::ProductReadout::new();
+        read!("product.vendor", product.vendor());
+        read!("product.family", product.family());
+        read!("product.product", product.product());
+        read!(
+            "product.product_marketing_name",
+            product.product_marketing_name()
+        );
+        read!("product.serial_number", product.serial_number());
+        read!("product.form_factor", product.form_factor());
+        read!("product.bios_date", product.bios_date());
+    }
+
+    let packages = crate::PackageReadout::new();
+    let counts = packages.count_pkgs();
+    if !counts.is_empty() {
+        let counts = counts
+            .iter()
+            .map(|(manager, count)| format!("{manager}: {count}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        values.insert("package.count_pkgs", counts);
+    }
+
+    let network = crate::NetworkReadout::new();
+    read!("network.logical_address", network.logical_address(None));
+    read!("network.physical_address", network.physical_address(None));
+    read!(
+        "network.tcp_connection_count",
+        network.tcp_connection_count()
+    );
+    read!(
+        "network.established_tcp_connection_count",
+        network.established_tcp_connection_count()
+    );
+    read!("network.vpn_active", network.vpn_active());
+
+    values
+}
+
+/// Queries every readout available on the host at once, spreading the work across threads
+/// so that slow readouts don't hold up the others.
+///
+/// Unlike [`read_all`], the values are returned as-is, along with the errors of the readouts
+/// that couldn't be read.
+pub fn collect_all() -> snapshot::ReadoutSnapshot {
+    use snapshot::*;
+    use traits::PackageReadout as _;
+
+    // Readouts aren't necessarily safe to share between threads, each thread creates its own.
+    std::thread::scope(|scope| {
+        let battery = scope.spawn(BatterySnapshot::collect);
+        let kernel = scope.spawn(KernelSnapshot::collect);
+        let memory = scope.spawn(MemorySnapshot::collect);
+        let product = scope.spawn(ProductSnapshot::collect);
+        let packages = scope.spawn(|| crate::PackageReadout::new().count_pkgs());
+        let network = scope.spawn(NetworkSnapshot::collect);
+        // The general readouts, which take the longest, spread themselves across more
+        // threads while being collected on this one.
+        let general = GeneralSnapshot::collect();
+
+        ReadoutSnapshot {
+            battery: join(battery),
+            kernel: join(kernel),
+            memory: join(memory),
+            general,
+            product: join(product),
+            packages: join(packages),
+            network: join(network),
+        }
+    })
 }
 
 #[cfg(feature = "version")]
@@ -105,5 +450,18 @@ pub fn version() -> &'static str {
     }
 }
 
+// NVIDIA's Management Library is only looked for on the platforms its driver supports.
+#[cfg(all(
+    feature = "nvml",
+    feature = "full",
+    any(
+        all(target_os = "linux", not(feature = "openwrt")),
+        target_os = "windows"
+    )
+))]
+mod nvml;
 mod shared;
+pub mod snapshot;
 pub mod traits;
+
+pub use shared::{format_uptime, UptimeFormat};
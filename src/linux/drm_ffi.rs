@@ -0,0 +1,52 @@
+use std::os::raw::*;
+
+/// The `DRM_IOWR(0xA0, struct drm_mode_card_res)` request of `<drm/drm.h>`.
+pub const DRM_IOCTL_MODE_GETRESOURCES: c_ulong = 0xC040_64A0;
+
+/// The `DRM_IOWR(0xAA, struct drm_mode_get_property)` request of `<drm/drm.h>`.
+pub const DRM_IOCTL_MODE_GETPROPERTY: c_ulong = 0xC040_64AA;
+
+/// The `DRM_IOWR(0xB9, struct drm_mode_obj_get_properties)` request of `<drm/drm.h>`.
+pub const DRM_IOCTL_MODE_OBJ_GETPROPERTIES: c_ulong = 0xC020_64B9;
+
+/// The type of the mode objects that are connectors.
+pub const DRM_MODE_OBJECT_CONNECTOR: u32 = 0xC0C0_C0C0;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct drm_mode_card_res {
+    pub fb_id_ptr: u64,
+    pub crtc_id_ptr: u64,
+    pub connector_id_ptr: u64,
+    pub encoder_id_ptr: u64,
+    pub count_fbs: u32,
+    pub count_crtcs: u32,
+    pub count_connectors: u32,
+    pub count_encoders: u32,
+    pub min_width: u32,
+    pub max_width: u32,
+    pub min_height: u32,
+    pub max_height: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct drm_mode_obj_get_properties {
+    pub props_ptr: u64,
+    pub prop_values_ptr: u64,
+    pub count_props: u32,
+    pub obj_id: u32,
+    pub obj_type: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct drm_mode_get_property {
+    pub values_ptr: u64,
+    pub enum_blob_ptr: u64,
+    pub prop_id: u32,
+    pub flags: u32,
+    pub name: [c_char; 32],
+    pub count_values: u32,
+    pub count_enum_blobs: u32,
+}
@@ -1,16 +1,24 @@
 #![allow(clippy::unnecessary_cast)]
+mod drm_ffi;
+mod nvme_ffi;
+#[cfg(feature = "full")]
 mod pci_devices;
 mod sysinfo_ffi;
 
-use self::pci_devices::get_pci_devices;
+#[cfg(feature = "full")]
+use self::pci_devices::{get_pci_devices, PciDevice};
 use crate::extra;
 use crate::extra::get_entries;
 use crate::extra::path_extension;
 use crate::shared;
 use crate::traits::*;
 use itertools::Itertools;
+use nvme_ffi::nvme_admin_cmd;
+#[cfg(feature = "full")]
 use pciid_parser::Database;
+#[cfg(feature = "full")]
 use regex::Regex;
+use std::collections::HashMap;
 use std::fs;
 use std::fs::read_dir;
 use std::fs::File;
@@ -20,6 +28,7 @@ use std::process::{Command, Stdio};
 use sysctl::{Ctl, Sysctl};
 use sysinfo_ffi::sysinfo;
 
+#[cfg(feature = "full")]
 impl From<sqlite::Error> for ReadoutError {
     fn from(e: sqlite::Error) -> Self {
         ReadoutError::Other(e.to_string())
@@ -40,11 +49,16 @@ pub struct LinuxMemoryReadout {
     sysinfo: sysinfo,
 }
 
+#[cfg(feature = "full")]
 pub struct LinuxBatteryReadout;
 pub struct LinuxProductReadout;
+#[cfg(feature = "full")]
 pub struct LinuxPackageReadout;
+#[cfg(feature = "full")]
 pub struct LinuxNetworkReadout;
+pub struct LinuxProcessReadout;
 
+#[cfg(feature = "full")]
 impl BatteryReadout for LinuxBatteryReadout {
     fn new() -> Self {
         LinuxBatteryReadout
@@ -56,7 +70,7 @@ impl BatteryReadout for LinuxBatteryReadout {
                 .into_iter()
                 .filter(|x| {
                     x.components()
-                        .last()
+                        .next_back()
                         .unwrap()
                         .as_os_str()
                         .to_string_lossy()
@@ -89,7 +103,7 @@ impl BatteryReadout for LinuxBatteryReadout {
                 .into_iter()
                 .filter(|x| {
                     x.components()
-                        .last()
+                        .next_back()
                         .unwrap()
                         .as_os_str()
                         .to_string_lossy()
@@ -104,7 +118,8 @@ impl BatteryReadout for LinuxBatteryReadout {
 
                 match &status_text[..] {
                     "charging" => return Ok(BatteryState::Charging),
-                    "discharging" | "full" => return Ok(BatteryState::Discharging),
+                    "discharging" => return Ok(BatteryState::Discharging),
+                    "full" => return Ok(BatteryState::Full),
                     s => {
                         return Err(ReadoutError::Other(format!(
                             "Got an unexpected value \"{s}\" reading battery status"
@@ -123,7 +138,7 @@ impl BatteryReadout for LinuxBatteryReadout {
                 .into_iter()
                 .filter(|x| {
                     !x.components()
-                        .last()
+                        .next_back()
                         .unwrap()
                         .as_os_str()
                         .to_string_lossy()
@@ -160,6 +175,76 @@ impl BatteryReadout for LinuxBatteryReadout {
 
         Err(ReadoutError::Other("No batteries detected.".to_string()))
     }
+
+    fn is_present(&self) -> bool {
+        battery_path().is_some()
+    }
+
+    fn manufacturer(&self) -> Result<String, ReadoutError> {
+        let battery = battery_path()
+            .ok_or_else(|| ReadoutError::Other("No batteries detected.".to_string()))?;
+
+        Ok(extra::pop_newline(fs::read_to_string(
+            battery.join("manufacturer"),
+        )?))
+    }
+
+    fn model(&self) -> Result<String, ReadoutError> {
+        let battery = battery_path()
+            .ok_or_else(|| ReadoutError::Other("No batteries detected.".to_string()))?;
+
+        Ok(extra::pop_newline(fs::read_to_string(
+            battery.join("model_name"),
+        )?))
+    }
+
+    fn energy_now(&self) -> Result<u64, ReadoutError> {
+        LinuxBatteryReadout::energy("now")
+    }
+
+    fn energy_full(&self) -> Result<u64, ReadoutError> {
+        LinuxBatteryReadout::energy("full")
+    }
+}
+
+#[cfg(feature = "full")]
+impl LinuxBatteryReadout {
+    /// Returns the energy of the first battery at the given point, _i.e._ `now` or `full`,
+    /// in microwatt-hours.
+    ///
+    /// Depending on the hardware, the kernel either reports the energy in µWh through
+    /// `energy_<point>`, or the charge in µAh through `charge_<point>`, in which case it's
+    /// multiplied by the battery's design voltage in µV.
+    fn energy(point: &str) -> Result<u64, ReadoutError> {
+        let battery = battery_path()
+            .ok_or_else(|| ReadoutError::Other("No batteries detected.".to_string()))?;
+
+        let read = |attribute: String| -> Result<u64, ReadoutError> {
+            let value = extra::pop_newline(fs::read_to_string(battery.join(&attribute))?);
+            value
+                .parse::<u64>()
+                .map_err(|e| ReadoutError::Other(format!("Could not parse {attribute}: {e}")))
+        };
+
+        if let Ok(energy) = read(format!("energy_{point}")) {
+            return Ok(energy);
+        }
+
+        let charge = read(format!("charge_{point}"))?;
+        let voltage = read(String::from("voltage_min_design"))?;
+
+        Ok(charge * voltage / 1_000_000)
+    }
+}
+
+/// Returns the first battery registered under `/sys/class/power_supply`.
+fn battery_path() -> Option<PathBuf> {
+    get_entries(Path::new("/sys/class/power_supply"))?
+        .into_iter()
+        .find(|x| {
+            x.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with("BAT"))
+        })
 }
 
 impl KernelReadout for LinuxKernelReadout {
@@ -171,11 +256,13 @@ impl KernelReadout for LinuxKernelReadout {
     }
 
     fn os_release(&self) -> Result<String, ReadoutError> {
-        Ok(self
+        let release = self
             .os_release_ctl
             .as_ref()
             .ok_or(ReadoutError::MetricNotAvailable)?
-            .value_string()?)
+            .value_string()?;
+
+        LinuxKernelReadout::release_from(&release)
     }
 
     fn os_type(&self) -> Result<String, ReadoutError> {
@@ -185,8 +272,26 @@ impl KernelReadout for LinuxKernelReadout {
             .ok_or(ReadoutError::MetricNotAvailable)?
             .value_string()?)
     }
+
+    fn kernel_parameters(&self) -> Result<String, ReadoutError> {
+        shared::kernel_parameters()
+    }
+}
+
+impl LinuxKernelReadout {
+    /// Returns the release held by `kernel.osrelease` as the kernel reports it, local version
+    /// suffix included, _e.g._ `6.8.0-31-generic`.
+    fn release_from(value: &str) -> Result<String, ReadoutError> {
+        let release = value.trim();
+        if release.is_empty() {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        Ok(release.to_string())
+    }
 }
 
+#[cfg(feature = "full")]
 impl NetworkReadout for LinuxNetworkReadout {
     fn new() -> Self {
         LinuxNetworkReadout
@@ -260,6 +365,22 @@ impl NetworkReadout for LinuxNetworkReadout {
         }
     }
 
+    fn tx_dropped(&self, interface: Option<&str>) -> Result<usize, ReadoutError> {
+        LinuxNetworkReadout::interface_statistic(interface, "tx_dropped")
+    }
+
+    fn rx_dropped(&self, interface: Option<&str>) -> Result<usize, ReadoutError> {
+        LinuxNetworkReadout::interface_statistic(interface, "rx_dropped")
+    }
+
+    fn tx_errors(&self, interface: Option<&str>) -> Result<usize, ReadoutError> {
+        LinuxNetworkReadout::interface_statistic(interface, "tx_errors")
+    }
+
+    fn rx_errors(&self, interface: Option<&str>) -> Result<usize, ReadoutError> {
+        LinuxNetworkReadout::interface_statistic(interface, "rx_errors")
+    }
+
     fn physical_address(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
         if let Some(ifname) = interface {
             let rx_file = PathBuf::from("/sys/class/net").join(ifname).join("address");
@@ -275,6 +396,152 @@ impl NetworkReadout for LinuxNetworkReadout {
     fn logical_address(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
         shared::logical_address(interface)
     }
+
+    fn broadcast_address(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
+        shared::broadcast_address(interface)
+    }
+
+    fn subnet_mask(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
+        shared::subnet_mask(interface)
+    }
+
+    fn interfaces(&self) -> Result<Vec<NetworkInterface>, ReadoutError> {
+        let addresses = if_addrs::get_if_addrs()?;
+        let entries =
+            get_entries(Path::new("/sys/class/net")).ok_or(ReadoutError::MetricNotAvailable)?;
+
+        let mut interfaces: Vec<NetworkInterface> = entries
+            .into_iter()
+            .filter_map(|path| {
+                let name = path.file_name()?.to_string_lossy().into_owned();
+                let read = |file: &str| {
+                    fs::read_to_string(path.join(file))
+                        .ok()
+                        .map(extra::pop_newline)
+                };
+
+                let ipv4 = addresses
+                    .iter()
+                    .filter(|i| i.name == name)
+                    .find(|i| matches!(i.addr, if_addrs::IfAddr::V4(_)))
+                    .map(|i| i.ip().to_string());
+                let ipv6 = addresses
+                    .iter()
+                    .filter(|i| i.name == name)
+                    .find(|i| matches!(i.addr, if_addrs::IfAddr::V6(_)))
+                    .map(|i| i.ip().to_string());
+
+                // The interface flags are given in hexadecimal, IFF_UP being the lowest bit.
+                let flags = read("flags")
+                    .and_then(|flags| u32::from_str_radix(flags.trim_start_matches("0x"), 16).ok());
+
+                Some(NetworkInterface {
+                    ipv4,
+                    ipv6,
+                    mac: read("address").filter(|address| !address.is_empty()),
+                    tx_bytes: read("statistics/tx_bytes").and_then(|b| b.parse().ok()),
+                    rx_bytes: read("statistics/rx_bytes").and_then(|b| b.parse().ok()),
+                    is_up: flags.is_some_and(|flags| flags & libc::IFF_UP as u32 != 0),
+                    mtu: read("mtu").and_then(|mtu| mtu.parse().ok()),
+                    name,
+                })
+            })
+            .collect();
+
+        interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(interfaces)
+    }
+
+    fn tcp_connection_count(&self) -> Result<usize, ReadoutError> {
+        LinuxNetworkReadout::tcp_connections(None)
+    }
+
+    fn established_tcp_connection_count(&self) -> Result<usize, ReadoutError> {
+        // The states are those of include/net/tcp_states.h, ESTABLISHED being the first.
+        LinuxNetworkReadout::tcp_connections(Some("01"))
+    }
+
+    fn vpn_active(&self) -> Result<bool, ReadoutError> {
+        // The interfaces created by OpenVPN, WireGuard and PPP based VPNs, among others.
+        const TUNNEL_PREFIXES: [&str; 4] = ["tun", "tap", "wg", "ppp"];
+
+        let entries =
+            get_entries(Path::new("/sys/class/net")).ok_or(ReadoutError::MetricNotAvailable)?;
+
+        Ok(entries.into_iter().any(|path| {
+            let is_tunnel = path.file_name().is_some_and(|name| {
+                let name = name.to_string_lossy();
+                TUNNEL_PREFIXES
+                    .iter()
+                    .any(|prefix| name.starts_with(prefix))
+            });
+
+            // The operational state of tunnels is usually "unknown", so look at whether
+            // they were brought up instead.
+            is_tunnel
+                && fs::read_to_string(path.join("flags"))
+                    .ok()
+                    .and_then(|flags| {
+                        u32::from_str_radix(extra::pop_newline(flags).trim_start_matches("0x"), 16)
+                            .ok()
+                    })
+                    .is_some_and(|flags| flags & libc::IFF_UP as u32 != 0)
+        }))
+    }
+}
+
+#[cfg(feature = "full")]
+impl LinuxNetworkReadout {
+    /// Counts the TCP sockets listed in `/proc/net/tcp` and `/proc/net/tcp6`, optionally
+    /// only those in the given state, which is written as a hexadecimal number.
+    fn tcp_connections(state: Option<&str>) -> Result<usize, ReadoutError> {
+        let mut count = 0;
+
+        for table in ["/proc/net/tcp", "/proc/net/tcp6"] {
+            let content = match fs::read_to_string(table) {
+                Ok(content) => content,
+                // The latter is missing when IPv6 is disabled.
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+
+            // Each socket is listed on a line of its own, after a header, e.g.
+            // "0: 0100007F:0277 00000000:0000 0A ...", the fourth column being its state.
+            count += content
+                .lines()
+                .skip(1)
+                .filter(|line| match state {
+                    Some(state) => line.split_whitespace().nth(3) == Some(state),
+                    None => !line.trim().is_empty(),
+                })
+                .count();
+        }
+
+        Ok(count)
+    }
+
+    /// Reads one of the counters the kernel keeps under
+    /// `/sys/class/net/<interface>/statistics`.
+    fn interface_statistic(
+        interface: Option<&str>,
+        statistic: &str,
+    ) -> Result<usize, ReadoutError> {
+        if let Some(ifname) = interface {
+            let file = PathBuf::from("/sys/class/net")
+                .join(ifname)
+                .join("statistics")
+                .join(statistic);
+            let content = std::fs::read_to_string(file)?;
+            extra::pop_newline(content)
+                .parse::<usize>()
+                .map_err(|e| ReadoutError::Other(format!("Could not parse {statistic}: {e}")))
+        } else {
+            Err(ReadoutError::Other(String::from(
+                "Please specify a network interface to query.",
+            )))
+        }
+    }
 }
 
 impl GeneralReadout for LinuxGeneralReadout {
@@ -348,6 +615,10 @@ impl GeneralReadout for LinuxGeneralReadout {
         shared::username()
     }
 
+    fn logged_in_user_names(&self) -> Result<Vec<String>, ReadoutError> {
+        shared::logged_in_user_names()
+    }
+
     fn hostname(&self) -> Result<String, ReadoutError> {
         Ok(self
             .hostname_ctl
@@ -360,13 +631,80 @@ impl GeneralReadout for LinuxGeneralReadout {
         use os_release::OsRelease;
         let content = OsRelease::new()?;
 
+        // SteamOS is built on top of Arch Linux, but should be named after itself whatever
+        // its NAME field holds.
+        let name = match content.id.as_str() {
+            "steamos" => String::from("SteamOS"),
+            _ => content.name,
+        };
+
         if !content.version.is_empty() {
-            return Ok(format!("{} {}", content.name, content.version));
+            return Ok(format!("{} {}", name, content.version));
         } else if !content.version_id.is_empty() {
-            return Ok(format!("{} {}", content.name, content.version_id));
+            return Ok(format!("{} {}", name, content.version_id));
+        }
+
+        Ok(name)
+    }
+
+    fn os_codename(&self) -> Result<String, ReadoutError> {
+        use os_release::OsRelease;
+
+        if let Ok(content) = OsRelease::new() {
+            if !content.version_codename.is_empty() {
+                return Ok(content.version_codename);
+            }
+        }
+
+        // Rolling release distributions don't have any codename to speak of, in which case
+        // lsb_release reports "n/a".
+        if extra::which("lsb_release") {
+            let output = Command::new("lsb_release")
+                .args(["--codename", "--short"])
+                .stderr(Stdio::null())
+                .output()?;
+
+            let codename = String::from_utf8(output.stdout)
+                .map_err(|e| ReadoutError::Other(e.to_string()))?
+                .trim()
+                .to_string();
+
+            if !codename.is_empty() && codename != "n/a" {
+                return Ok(codename);
+            }
+        }
+
+        Err(ReadoutError::MetricNotAvailable)
+    }
+
+    fn steamos_version(&self) -> Result<String, ReadoutError> {
+        use os_release::OsRelease;
+        let content = OsRelease::new()?;
+
+        if content.id != "steamos" || content.version_id.is_empty() {
+            return Err(ReadoutError::MetricNotAvailable);
         }
 
-        Ok(content.name)
+        Ok(content.version_id)
+    }
+
+    fn os_logo_name(&self) -> Result<String, ReadoutError> {
+        use os_release::OsRelease;
+        let content = OsRelease::new()?;
+
+        // Unlike the fields known to os_release, extra ones still hold their quotes.
+        if let Some(logo) = content.extra.get("LOGO") {
+            let logo = logo.trim_matches(|c| c == '"' || c == '\'');
+            if !logo.is_empty() {
+                return Ok(logo.to_string());
+            }
+        }
+
+        if content.id.is_empty() {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        Ok(content.id)
     }
 
     fn desktop_environment(&self) -> Result<String, ReadoutError> {
@@ -381,6 +719,20 @@ impl GeneralReadout for LinuxGeneralReadout {
         shared::window_manager()
     }
 
+    fn wayland_compositor(&self) -> Result<String, ReadoutError> {
+        crate::winman::detect_wayland_compositor()
+    }
+
+    fn display_server_version(&self) -> Result<String, ReadoutError> {
+        use crate::winman::*;
+
+        match shared::session().as_deref() {
+            Ok("X11") => detect_xorg_version(),
+            Ok("Wayland") => detect_wayland_compositor_version(),
+            _ => Err(ReadoutError::NotImplemented),
+        }
+    }
+
     fn terminal(&self) -> Result<String, ReadoutError> {
         // This function returns the PPID of a given PID:
         //  - The file used to extract this data: /proc/<pid>/status
@@ -448,6 +800,85 @@ impl GeneralReadout for LinuxGeneralReadout {
         Ok(terminal)
     }
 
+    fn system_language(&self) -> Result<String, ReadoutError> {
+        // LANGUAGE holds a list of languages in order of preference, e.g. "en_US:en", and
+        // takes precedence over the locale the messages are displayed in.
+        let language = std::env::var("LANGUAGE")
+            .ok()
+            .and_then(|list| list.split(':').find_map(shared::locale_to_language_tag));
+
+        if let Some(language) = language {
+            return Ok(language);
+        }
+
+        let language = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find_map(|locale| shared::locale_to_language_tag(&locale));
+
+        if let Some(language) = language {
+            return Ok(language);
+        }
+
+        // Otherwise, fall back to the system-wide locale, which localectl reports as e.g.
+        //    System Locale: LANG=en_US.UTF-8
+        //                   LC_MESSAGES=en_GB.UTF-8
+        if !extra::which("localectl") {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        let output = Command::new("localectl")
+            .arg("status")
+            .stderr(Stdio::null())
+            .output()?;
+
+        let status = String::from_utf8_lossy(&output.stdout);
+        let locale = |key: &str| {
+            status.lines().find_map(|line| {
+                let line = line.trim();
+                let line = line.strip_prefix("System Locale:").unwrap_or(line).trim();
+                line.strip_prefix(key)
+                    .and_then(shared::locale_to_language_tag)
+            })
+        };
+
+        locale("LC_MESSAGES=")
+            .or_else(|| locale("LANG="))
+            .ok_or(ReadoutError::MetricNotAvailable)
+    }
+
+    fn hibernation_supported(&self) -> Result<bool, ReadoutError> {
+        // The ways the kernel can power off after writing the hibernation image, e.g.
+        // "[platform] shutdown reboot suspend test_resume". The file is missing altogether
+        // from kernels built without hibernation support.
+        let modes = match fs::read_to_string("/sys/power/disk") {
+            Ok(modes) => modes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(modes
+            .split_whitespace()
+            .map(|mode| mode.trim_matches(['[', ']']))
+            .any(|mode| mode == "platform" || mode == "shutdown"))
+    }
+
+    fn thunderbolt_security_level(&self) -> Result<String, ReadoutError> {
+        // The domain is the host's Thunderbolt controller, and only exists when the
+        // thunderbolt module is loaded for one.
+        match fs::read_to_string("/sys/bus/thunderbolt/devices/domain0/security") {
+            Ok(level) => Ok(extra::pop_newline(level)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                Err(ReadoutError::MetricNotAvailable)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn terminal_size(&self) -> Result<(u16, u16), ReadoutError> {
+        shared::terminal_size()
+    }
+
     fn shell(&self, format: ShellFormat, kind: ShellKind) -> Result<String, ReadoutError> {
         shared::shell(format, kind)
     }
@@ -474,6 +905,58 @@ impl GeneralReadout for LinuxGeneralReadout {
         ))
     }
 
+    fn cpu_usage_sampled(&self, interval: std::time::Duration) -> Result<usize, ReadoutError> {
+        let (idle_before, total_before) = LinuxGeneralReadout::cpu_times()?;
+        std::thread::sleep(interval);
+        let (idle_after, total_after) = LinuxGeneralReadout::cpu_times()?;
+
+        let total = total_after.saturating_sub(total_before);
+        let idle = idle_after.saturating_sub(idle_before);
+
+        if total == 0 {
+            return Err(ReadoutError::Other(String::from(
+                "The sampling interval was too short to measure CPU usage.",
+            )));
+        }
+
+        Ok(((total - idle.min(total)) as f64 / total as f64 * 100.0).round() as usize)
+    }
+
+    fn cpu_usage_breakdown(
+        &self,
+        interval: std::time::Duration,
+    ) -> Result<CpuUsageBreakdown, ReadoutError> {
+        let before = LinuxGeneralReadout::cpu_time_counters()?;
+        std::thread::sleep(interval);
+        let after = LinuxGeneralReadout::cpu_time_counters()?;
+
+        let mut deltas = [0; 8];
+        for (delta, (after, before)) in deltas.iter_mut().zip(after.iter().zip(before)) {
+            *delta = after.saturating_sub(before);
+        }
+
+        let total: u64 = deltas.iter().sum();
+        if total == 0 {
+            return Err(ReadoutError::Other(String::from(
+                "The sampling interval was too short to measure CPU usage.",
+            )));
+        }
+
+        let [user, nice, system, idle, iowait, irq, softirq, steal] =
+            deltas.map(|delta| delta as f32 / total as f32 * 100.0);
+
+        Ok(CpuUsageBreakdown {
+            user,
+            nice,
+            system,
+            idle,
+            iowait,
+            irq,
+            softirq,
+            steal,
+        })
+    }
+
     fn cpu_physical_cores(&self) -> Result<usize, ReadoutError> {
         use std::io::{BufRead, BufReader};
         if let Ok(content) = File::open("/proc/cpuinfo") {
@@ -498,6 +981,61 @@ impl GeneralReadout for LinuxGeneralReadout {
         Ok(unsafe { libc::sysconf(libc::_SC_NPROCESSORS_CONF) } as usize)
     }
 
+    fn cpu_topology(&self) -> Result<CpuTopology, ReadoutError> {
+        let mut sockets: Vec<i64> = vec![];
+        let mut threads: HashMap<(i64, i64), usize> = HashMap::new();
+
+        // Offline processors don't have a topology directory.
+        for entry in read_dir("/sys/devices/system/cpu")?.flatten() {
+            let name = entry.file_name();
+            let is_cpu = name
+                .to_str()
+                .and_then(|n| n.strip_prefix("cpu"))
+                .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()));
+            if !is_cpu {
+                continue;
+            }
+
+            let topology = entry.path().join("topology");
+            let id = |name: &str| {
+                fs::read_to_string(topology.join(name))
+                    .ok()
+                    .and_then(|id| id.trim().parse::<i64>().ok())
+            };
+
+            if let (Some(socket), Some(core)) = (id("physical_package_id"), id("core_id")) {
+                if !sockets.contains(&socket) {
+                    sockets.push(socket);
+                }
+
+                *threads.entry((socket, core)).or_default() += 1;
+            }
+        }
+
+        if threads.is_empty() {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        Ok(CpuTopology {
+            sockets: sockets.len(),
+            cores_per_socket: threads.len() / sockets.len(),
+            threads_per_core: threads.values().copied().max().unwrap_or(1),
+            total_threads: threads.values().sum(),
+        })
+    }
+
+    fn cpu_max_frequency(&self) -> Result<u64, ReadoutError> {
+        // The frequency is given in kHz.
+        let max_freq = extra::pop_newline(fs::read_to_string(
+            "/sys/devices/system/cpu/cpu0/cpufreq/cpuinfo_max_freq",
+        )?);
+
+        max_freq
+            .parse::<u64>()
+            .map(|khz| khz / 1000)
+            .map_err(|e| ReadoutError::Other(format!("Could not parse the CPU frequency: {e}")))
+    }
+
     fn uptime(&self) -> Result<usize, ReadoutError> {
         let mut info = self.sysinfo;
         let info_ptr: *mut sysinfo = &mut info;
@@ -547,6 +1085,7 @@ impl GeneralReadout for LinuxGeneralReadout {
         shared::disk_space(String::from("/"))
     }
 
+    #[cfg(feature = "full")]
     fn gpus(&self) -> Result<Vec<String>, ReadoutError> {
         let db = match Database::read() {
             Ok(db) => db,
@@ -556,11 +1095,27 @@ impl GeneralReadout for LinuxGeneralReadout {
         let devices = get_pci_devices()?;
         let mut gpus = vec![];
 
+        // NVML knows the marketing names of NVIDIA's GPUs, which are more precise than those
+        // of the PCI ID database.
+        #[cfg(feature = "nvml")]
+        let nvml_gpus = crate::nvml::Nvml::load()
+            .map(|nvml| nvml.gpus())
+            .unwrap_or_default();
+
         for device in devices {
             if !device.is_gpu(&db) {
                 continue;
             };
 
+            #[cfg(feature = "nvml")]
+            if let Some(gpu) = nvml_gpus
+                .iter()
+                .find(|gpu| gpu.pci_address.is_some() && gpu.pci_address == device.address())
+            {
+                gpus.push(gpu.name.clone());
+                continue;
+            }
+
             if let Some(sub_device_name) = device.get_device_name(&db) {
                 gpus.push(sub_device_name);
             };
@@ -572,61 +1127,1466 @@ impl GeneralReadout for LinuxGeneralReadout {
             Ok(gpus)
         }
     }
-}
 
-impl MemoryReadout for LinuxMemoryReadout {
-    fn new() -> Self {
-        LinuxMemoryReadout {
-            sysinfo: sysinfo::new(),
-        }
+    #[cfg(not(feature = "full"))]
+    fn gpus(&self) -> Result<Vec<String>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
     }
 
-    fn total(&self) -> Result<u64, ReadoutError> {
-        let mut info = self.sysinfo;
-        let info_ptr: *mut sysinfo = &mut info;
-        let ret = unsafe { sysinfo(info_ptr) };
-        if ret != -1 {
-            Ok(info.totalram as u64 * info.mem_unit as u64 / 1024)
-        } else {
-            Err(ReadoutError::Other(
-                "Something went wrong during the initialization of the sysinfo struct.".to_string(),
-            ))
-        }
+    #[cfg(feature = "full")]
+    fn gpu_count(&self) -> Result<usize, ReadoutError> {
+        let devices = get_pci_devices()?;
+        Ok(devices
+            .iter()
+            .filter(|device| device.is_display_controller())
+            .count())
     }
 
-    fn free(&self) -> Result<u64, ReadoutError> {
-        let mut info = self.sysinfo;
-        let info_ptr: *mut sysinfo = &mut info;
-        let ret = unsafe { sysinfo(info_ptr) };
-        if ret != -1 {
-            Ok(info.freeram as u64 * info.mem_unit as u64 / 1024)
+    #[cfg(feature = "full")]
+    fn gpu_temp(&self) -> Result<Vec<(String, f32)>, ReadoutError> {
+        let db = Database::read().ok();
+        let mut temperatures = vec![];
+
+        for card in LinuxGeneralReadout::drm_cards() {
+            // GPU drivers that report their temperature (e.g. amdgpu, nouveau, i915)
+            // register a hwmon device underneath the card's PCI device.
+            let Some(hwmon) = get_entries(&card.join("device/hwmon"))
+                .and_then(|entries| entries.into_iter().next())
+            else {
+                continue;
+            };
+
+            let Ok(input) = fs::read_to_string(hwmon.join("temp1_input")) else {
+                continue;
+            };
+
+            // The value is reported in millidegrees Celsius.
+            if let Ok(temperature) = extra::pop_newline(input).parse::<f32>() {
+                let name = LinuxGeneralReadout::gpu_name(&card, db.as_ref());
+                temperatures.push((name, temperature / 1000.0));
+            }
+        }
+
+        // NVIDIA's proprietary driver doesn't register a hwmon device.
+        #[cfg(feature = "nvml")]
+        crate::nvml::Nvml::merge_temperatures(&mut temperatures);
+
+        if temperatures.is_empty() {
+            Err(ReadoutError::MetricNotAvailable)
         } else {
-            Err(ReadoutError::Other(
-                "Something went wrong during the initialization of the sysinfo struct.".to_string(),
-            ))
+            Ok(temperatures)
         }
     }
 
-    fn buffers(&self) -> Result<u64, ReadoutError> {
-        let mut info = self.sysinfo;
-        let info_ptr: *mut sysinfo = &mut info;
-        let ret = unsafe { sysinfo(info_ptr) };
-        if ret != -1 {
-            Ok(info.bufferram as u64 * info.mem_unit as u64 / 1024)
+    #[cfg(feature = "full")]
+    fn gpu_driver_name(&self) -> Result<Vec<(String, String)>, ReadoutError> {
+        let db = Database::read().ok();
+        let mut drivers = vec![];
+
+        for card in LinuxGeneralReadout::drm_cards() {
+            // The driver of the card's PCI device links to the module that's bound to it,
+            // e.g. /sys/bus/pci/drivers/amdgpu.
+            let Ok(driver) = fs::read_link(card.join("device/driver")) else {
+                continue;
+            };
+
+            if let Some(module) = driver.file_name() {
+                let name = LinuxGeneralReadout::gpu_name(&card, db.as_ref());
+                drivers.push((name, module.to_string_lossy().into_owned()));
+            }
+        }
+
+        if drivers.is_empty() {
+            Err(ReadoutError::MetricNotAvailable)
         } else {
-            Err(ReadoutError::Other(
-                "Something went wrong during the initialization of the sysinfo struct.".to_string(),
-            ))
+            Ok(drivers)
         }
     }
 
-    fn cached(&self) -> Result<u64, ReadoutError> {
-        Ok(shared::get_meminfo_value("Cached"))
-    }
+    #[cfg(feature = "full")]
+    fn gpu_vram(&self) -> Result<Vec<(String, u64)>, ReadoutError> {
+        let db = Database::read().ok();
+        let mut vram = vec![];
+        let mut nvidia = false;
+
+        for card in LinuxGeneralReadout::drm_cards() {
+            // amdgpu reports the size of the card's memory, in bytes.
+            if let Ok(total) = fs::read_to_string(card.join("device/mem_info_vram_total")) {
+                if let Ok(bytes) = extra::pop_newline(total).parse::<u64>() {
+                    let name = LinuxGeneralReadout::gpu_name(&card, db.as_ref());
+                    vram.push((name, bytes / 1024 / 1024));
+                }
+            } else if fs::read_link(card.join("device/driver"))
+                .is_ok_and(|driver| driver.ends_with("nvidia"))
+            {
+                nvidia = true;
+            }
+        }
 
-    fn reclaimable(&self) -> Result<u64, ReadoutError> {
-        Ok(shared::get_meminfo_value("SReclaimable"))
-    }
+        // NVIDIA's proprietary driver only reports it through its own tools.
+        if nvidia {
+            let count = vram.len();
+
+            #[cfg(feature = "nvml")]
+            crate::nvml::Nvml::merge_vram(&mut vram);
+
+            if vram.len() == count {
+                vram.extend(LinuxGeneralReadout::nvidia_smi_vram());
+            }
+        }
+
+        if vram.is_empty() {
+            Err(ReadoutError::MetricNotAvailable)
+        } else {
+            Ok(vram)
+        }
+    }
+
+    fn cpu_temperature(&self) -> Result<f32, ReadoutError> {
+        // Labels given by the coretemp (Intel) and k10temp (AMD) drivers to the sensor that
+        // covers the whole CPU package, in order of preference.
+        const LABELS: [&str; 3] = ["Package id 0", "Tdie", "Tctl"];
+
+        let mut sensors: Vec<(usize, PathBuf)> = vec![];
+        for hwmon in get_entries(Path::new("/sys/class/hwmon")).unwrap_or_default() {
+            for entry in get_entries(&hwmon).unwrap_or_default() {
+                let Some(sensor) = entry
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .and_then(|name| name.strip_suffix("_label"))
+                    .filter(|sensor| sensor.starts_with("temp"))
+                else {
+                    continue;
+                };
+
+                let Ok(label) = fs::read_to_string(&entry) else {
+                    continue;
+                };
+
+                if let Some(rank) = LABELS.iter().position(|l| *l == label.trim()) {
+                    sensors.push((rank, hwmon.join(format!("{sensor}_input"))));
+                }
+            }
+        }
+
+        sensors.sort_by_key(|(rank, _)| *rank);
+
+        for (_, input) in sensors {
+            let Ok(input) = fs::read_to_string(input) else {
+                continue;
+            };
+
+            // The value is reported in millidegrees Celsius.
+            if let Ok(temperature) = extra::pop_newline(input).parse::<f32>() {
+                return Ok(temperature / 1000.0);
+            }
+        }
+
+        Err(ReadoutError::MetricNotAvailable)
+    }
+
+    fn cpu_throttled(&self) -> Result<bool, ReadoutError> {
+        if let Some(throttled) = LinuxGeneralReadout::raspberry_pi_throttled() {
+            return Ok(throttled);
+        }
+
+        // The thermal framework limits the processor through cooling devices, whose state
+        // rises above zero for as long as they are active.
+        let mut found = false;
+        for device in get_entries(Path::new("/sys/class/thermal")).unwrap_or_default() {
+            let Ok(kind) = fs::read_to_string(device.join("type")) else {
+                continue;
+            };
+
+            let kind = kind.trim();
+            if kind != "Processor" && !kind.starts_with("cpufreq-") {
+                continue;
+            }
+
+            if let Ok(state) = fs::read_to_string(device.join("cur_state")) {
+                found = true;
+                if extra::pop_newline(state)
+                    .parse::<u64>()
+                    .is_ok_and(|s| s > 0)
+                {
+                    return Ok(true);
+                }
+            }
+        }
+
+        if found {
+            Ok(false)
+        } else {
+            Err(ReadoutError::MetricNotAvailable)
+        }
+    }
+
+    fn keyboard_layout(&self) -> Result<String, ReadoutError> {
+        // The layout set on the X server, which Xwayland follows too, takes precedence over
+        // the system-wide default.
+        if std::env::var_os("DISPLAY").is_some() && extra::which("setxkbmap") {
+            let output = Command::new("setxkbmap")
+                .arg("-query")
+                .stderr(Stdio::null())
+                .output()?;
+
+            let layout = String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .find_map(|line| line.strip_prefix("layout:"))
+                .map(|layout| layout.trim().to_string());
+
+            if let Some(layout) = layout.filter(|layout| !layout.is_empty()) {
+                return Ok(layout);
+            }
+        }
+
+        // Debian and its derivatives keep the default in /etc/default/keyboard, whereas
+        // localectl writes it to a snippet of the X server's configuration, e.g.
+        // Option "XkbLayout" "us"
+        let sources = [
+            ("/etc/default/keyboard", "XKBLAYOUT="),
+            (
+                "/etc/X11/xorg.conf.d/00-keyboard.conf",
+                "Option \"XkbLayout\"",
+            ),
+        ];
+
+        for (path, key) in sources {
+            let Ok(content) = fs::read_to_string(path) else {
+                continue;
+            };
+
+            let layout = content
+                .lines()
+                .find_map(|line| line.trim().strip_prefix(key))
+                .map(|layout| layout.trim().trim_matches('"'));
+
+            if let Some(layout) = layout.filter(|layout| !layout.is_empty()) {
+                return Ok(layout.to_string());
+            }
+        }
+
+        Err(ReadoutError::MetricNotAvailable)
+    }
+
+    fn default_audio_output(&self) -> Result<String, ReadoutError> {
+        // pactl talks to PulseAudio, as well as PipeWire through pipewire-pulse.
+        if !extra::which("pactl") {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        let output = Command::new("pactl")
+            .arg("get-default-sink")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .output()?;
+
+        let sink = extra::pop_newline(String::from_utf8_lossy(&output.stdout));
+        if !output.status.success() || sink.is_empty() || sink == "auto_null" {
+            return Err(ReadoutError::Warning(String::from(
+                "No audio output device is available.",
+            )));
+        }
+
+        let output = Command::new("pactl")
+            .args(["list", "sinks"])
+            .env("LC_ALL", "C")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .output()?;
+
+        // Each sink is listed as a block of "Key: Value" lines, the one following its
+        // "Name" is a human-readable description of the device.
+        let sinks = String::from_utf8_lossy(&output.stdout);
+        let description = sinks
+            .lines()
+            .map(str::trim)
+            .skip_while(|line| line.strip_prefix("Name: ") != Some(sink.as_str()))
+            .find_map(|line| line.strip_prefix("Description: "));
+
+        Ok(description.map(String::from).unwrap_or(sink))
+    }
+
+    fn raid_status(&self) -> Result<Vec<(String, String)>, ReadoutError> {
+        let mdstat = fs::read_to_string("/proc/mdstat")?;
+        let mut arrays: Vec<(String, String)> = vec![];
+
+        // Each array starts with a line such as "md0 : active raid1 sdb1[1] sda1[0]",
+        // followed by indented lines describing its members and any ongoing sync, e.g.
+        // "1953382464 blocks super 1.2 [2/1] [U_]" where an underscore marks a missing disk.
+        for line in mdstat.lines() {
+            if let Some((device, description)) = line.split_once(" : ") {
+                if device.starts_with("md") {
+                    let state = if description.starts_with("inactive") {
+                        "inactive"
+                    } else {
+                        "clean"
+                    };
+
+                    arrays.push((device.to_string(), state.to_string()));
+                }
+
+                continue;
+            }
+
+            let Some((_, state)) = arrays.last_mut() else {
+                continue;
+            };
+
+            if state == "inactive" {
+                continue;
+            }
+
+            let line = line.trim_start();
+            if line.contains("blocks") && line.rsplit('[').next().is_some_and(|s| s.contains('_')) {
+                *state = String::from("degraded");
+            } else if line.contains("recovery =") {
+                *state = String::from("recovering");
+            } else if line.contains("resync =") && state == "clean" {
+                *state = String::from("resyncing");
+            }
+        }
+
+        if arrays.is_empty() {
+            Err(ReadoutError::MetricNotAvailable)
+        } else {
+            Ok(arrays)
+        }
+    }
+
+    fn pending_updates(&self) -> Result<usize, ReadoutError> {
+        LinuxGeneralReadout::package_updates()
+    }
+
+    fn disk_io(&self) -> Result<(u64, u64), ReadoutError> {
+        // The kernel counts sectors of 512 bytes, whatever the actual sector size of the disk.
+        const SECTOR_SIZE: u64 = 512;
+
+        let diskstats = fs::read_to_string("/proc/diskstats")?;
+        let (mut read, mut written) = (0, 0);
+
+        for line in diskstats.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (Some(name), Some(sectors_read), Some(sectors_written)) =
+                (fields.get(2), fields.get(5), fields.get(9))
+            else {
+                continue;
+            };
+
+            // Only physical disks are backed by a device, which leaves out partitions, loop
+            // devices and device-mapper targets whose I/O would otherwise be counted twice.
+            if !Path::new("/sys/block").join(name).join("device").exists() {
+                continue;
+            }
+
+            read += sectors_read.parse::<u64>().unwrap_or(0) * SECTOR_SIZE;
+            written += sectors_written.parse::<u64>().unwrap_or(0) * SECTOR_SIZE;
+        }
+
+        Ok((read, written))
+    }
+
+    fn removable_drives(&self) -> Result<Vec<DriveInfo>, ReadoutError> {
+        let mounts = fs::read_to_string("/proc/mounts")?;
+        let mut drives = vec![];
+
+        for line in mounts.lines() {
+            let mut fields = line.split_whitespace();
+            let (Some(device), Some(mount_point)) = (fields.next(), fields.next()) else {
+                continue;
+            };
+
+            if !device.starts_with("/dev/") {
+                continue;
+            }
+
+            let device = Path::new(device);
+            if LinuxGeneralReadout::disk_attribute(device, "removable").as_deref() != Some("1") {
+                continue;
+            }
+
+            let mount_point = unescape_mount_field(mount_point);
+            let Ok((used, total)) = shared::disk_space(mount_point.clone()) else {
+                continue;
+            };
+
+            drives.push(DriveInfo {
+                label: LinuxGeneralReadout::filesystem_label(device),
+                mount_point,
+                free: total - used,
+                total,
+            });
+        }
+
+        Ok(drives)
+    }
+
+    fn disk_model(&self, path: &Path) -> Result<String, ReadoutError> {
+        let mut device = PathBuf::from(LinuxGeneralReadout::mount_device(path)?);
+
+        // Device-mapper targets, e.g. encrypted or LVM volumes, sit on top of the
+        // partition that holds them.
+        while let Some(slave) = LinuxGeneralReadout::disk_slave(&device) {
+            device = slave;
+        }
+
+        let model = LinuxGeneralReadout::disk_attribute(&device, "device/model")
+            .map(|model| model.trim().to_string())
+            .filter(|model| !model.is_empty())
+            .ok_or(ReadoutError::MetricNotAvailable)?;
+
+        let block =
+            LinuxGeneralReadout::sysfs_block(&device).ok_or(ReadoutError::MetricNotAvailable)?;
+        let block = block.to_string_lossy();
+
+        // NVMe namespaces hang off an "nvme" controller, and SATA disks off an "ata" port.
+        if block.contains("/nvme") {
+            Ok(format!("NVMe {model}"))
+        } else if block.contains("/ata") {
+            Ok(format!("SATA {model}"))
+        } else {
+            Ok(model)
+        }
+    }
+
+    fn nvme_health_percentage(&self, drive: &str) -> Result<u8, ReadoutError> {
+        const LOG_PAGE_SIZE: usize = 512;
+
+        let file = match File::open(drive) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+                return Err(ReadoutError::PermissionDenied(String::from(
+                    "Reading the health of an NVMe drive requires root privileges.",
+                )))
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut log_page = [0u8; LOG_PAGE_SIZE];
+        let number_of_dwords = (LOG_PAGE_SIZE / 4) as u32;
+        let mut cmd = nvme_admin_cmd {
+            opcode: nvme_ffi::NVME_ADMIN_GET_LOG_PAGE,
+            // The SMART log page is kept for the controller as a whole.
+            nsid: u32::MAX,
+            addr: log_page.as_mut_ptr() as u64,
+            data_len: LOG_PAGE_SIZE as u32,
+            cdw10: ((number_of_dwords - 1) << 16) | nvme_ffi::NVME_LOG_SMART,
+            ..Default::default()
+        };
+
+        let ret = unsafe {
+            libc::ioctl(
+                std::os::unix::io::AsRawFd::as_raw_fd(&file),
+                nvme_ffi::NVME_IOCTL_ADMIN_CMD as _,
+                &mut cmd,
+            )
+        };
+
+        if ret != 0 {
+            let error = std::io::Error::last_os_error();
+            return match error.raw_os_error() {
+                // Devices that aren't NVMe drives don't know about the request.
+                Some(libc::ENOTTY) | Some(libc::EINVAL) => Err(ReadoutError::MetricNotAvailable),
+                Some(libc::EACCES) | Some(libc::EPERM) => Err(ReadoutError::PermissionDenied(
+                    String::from("Reading the health of an NVMe drive requires root privileges."),
+                )),
+                _ => Err(error.into()),
+            };
+        }
+
+        // The "Percentage Used" estimate of the drive's life is the sixth byte of the log
+        // page, and may exceed 100 once the drive has outlived its rated endurance.
+        Ok(100 - log_page[5].min(100))
+    }
+
+    fn disk_health(&self, device: &str) -> Result<DiskHealth, ReadoutError> {
+        if !extra::which("smartctl") {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        let output = Command::new("smartctl")
+            .args(["--health", "--quietmode=errorsonly", device])
+            .stderr(Stdio::null())
+            .output()?;
+
+        // smartctl reports its findings through the bits of its exit status, see the
+        // "RETURN VALUES" section of smartctl(8).
+        let status = output
+            .status
+            .code()
+            .ok_or(ReadoutError::MetricNotAvailable)?;
+
+        if status & 0b01 != 0 {
+            return Err(ReadoutError::Other(String::from(
+                "smartctl failed to parse its command line.",
+            )));
+        }
+
+        if status & 0b10 != 0 {
+            // The device couldn't be opened, which is what happens to unprivileged users.
+            if unsafe { libc::geteuid() } != 0 {
+                return Err(ReadoutError::PermissionDenied(String::from(
+                    "Reading the health of a disk requires root privileges.",
+                )));
+            }
+
+            return Err(ReadoutError::Other(format!(
+                "smartctl failed to open {device}."
+            )));
+        }
+
+        Ok(match status {
+            s if s & 0b0001_1000 != 0 => DiskHealth::Failing,
+            s if s & 0b1110_0000 != 0 => DiskHealth::Warning,
+            s if s & 0b0000_0100 != 0 => DiskHealth::Unknown,
+            _ => DiskHealth::Ok,
+        })
+    }
+
+    fn monitor_names(&self) -> Result<Vec<String>, ReadoutError> {
+        let names = LinuxGeneralReadout::connected_edids()?
+            .iter()
+            .filter_map(|edid| LinuxGeneralReadout::edid_monitor_name(edid))
+            .collect();
+
+        Ok(names)
+    }
+
+    fn battery_charging_speed(&self) -> Result<f32, ReadoutError> {
+        let battery = battery_path()
+            .ok_or_else(|| ReadoutError::Other("No batteries detected.".to_string()))?;
+
+        let status = extra::pop_newline(fs::read_to_string(battery.join("status"))?);
+        if !status.eq_ignore_ascii_case("charging") {
+            return Ok(0.0);
+        }
+
+        let read = |attribute: &str| {
+            fs::read_to_string(battery.join(attribute))
+                .ok()
+                .and_then(|value| value.trim().parse::<f64>().ok())
+        };
+
+        // Some batteries report their power in µW, others only their current in µA and
+        // voltage in µV.
+        let microwatts = match (read("power_now"), read("current_now"), read("voltage_now")) {
+            (Some(power), _, _) => power,
+            (None, Some(current), Some(voltage)) => current * voltage / 1_000_000.0,
+            _ => return Err(ReadoutError::MetricNotAvailable),
+        };
+
+        Ok((microwatts.abs() / 1_000_000.0) as f32)
+    }
+
+    fn connected_monitors_info(&self) -> Result<Vec<MonitorInfo>, ReadoutError> {
+        let color_depth = self.color_depth().map(u32::from).unwrap_or_default();
+
+        // The X server, XWayland included, knows which modes are in use and which output
+        // is the primary one.
+        if std::env::var_os("DISPLAY").is_some() && extra::which("xrandr") {
+            let output = Command::new("xrandr")
+                .arg("--query")
+                .stderr(Stdio::null())
+                .output()?;
+
+            if output.status.success() {
+                let monitors = LinuxGeneralReadout::parse_xrandr(
+                    &String::from_utf8_lossy(&output.stdout),
+                    color_depth,
+                );
+
+                if !monitors.is_empty() {
+                    return Ok(monitors);
+                }
+            }
+        }
+
+        // Otherwise, fall back to the preferred timing advertised by the EDID of each
+        // display connected to a DRM connector, the first of which stands in for the primary
+        // one.
+        let mut entries = get_entries(Path::new("/sys/class/drm")).ok_or(ReadoutError::Other(
+            String::from("Could not read the connectors listed in /sys/class/drm"),
+        ))?;
+        entries.sort();
+
+        let monitors = entries
+            .into_iter()
+            .filter(|entry| {
+                fs::read_to_string(entry.join("status")).is_ok_and(|s| s.trim() == "connected")
+            })
+            .filter_map(|entry| {
+                let edid = fs::read(entry.join("edid")).ok()?;
+                let (resolution, refresh_rate) = LinuxGeneralReadout::edid_preferred_timing(&edid)?;
+
+                // Connectors are named after the card they belong to, e.g. "card0-HDMI-A-1".
+                let connector = entry.file_name()?.to_string_lossy().into_owned();
+                let name =
+                    LinuxGeneralReadout::edid_monitor_name(&edid).unwrap_or_else(
+                        || match connector.split_once('-') {
+                            Some((_, output)) => output.to_string(),
+                            None => connector,
+                        },
+                    );
+
+                Some(MonitorInfo {
+                    name,
+                    resolution,
+                    refresh_rate,
+                    is_primary: false,
+                    color_depth,
+                })
+            })
+            .enumerate()
+            .map(|(i, monitor)| MonitorInfo {
+                is_primary: i == 0,
+                ..monitor
+            })
+            .collect();
+
+        Ok(monitors)
+    }
+
+    fn color_depth(&self) -> Result<u8, ReadoutError> {
+        // The depth of the root window is that of the X screen, XWayland included.
+        if std::env::var_os("DISPLAY").is_some() && extra::which("xdpyinfo") {
+            let output = Command::new("xdpyinfo").stderr(Stdio::null()).output()?;
+            let depth = String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .find_map(|line| line.trim().strip_prefix("depth of root window:"))
+                .and_then(|depth| depth.split_whitespace().next()?.parse::<u8>().ok());
+
+            if let Some(depth) = depth {
+                return Ok(depth);
+            }
+        }
+
+        // Otherwise, the framebuffer the kernel's DRM driver emulates has the depth of the
+        // console.
+        let bpp = fs::read_to_string("/sys/class/graphics/fb0/bits_per_pixel")
+            .map_err(|_| ReadoutError::MetricNotAvailable)?;
+
+        extra::pop_newline(bpp)
+            .parse::<u8>()
+            .map_err(|e| ReadoutError::Other(format!("Could not parse the color depth: {e}")))
+    }
+
+    fn screen_is_hdr(&self) -> Result<bool, ReadoutError> {
+        let mut cards: Vec<PathBuf> = get_entries(Path::new("/dev/dri"))
+            .ok_or(ReadoutError::MetricNotAvailable)?
+            .into_iter()
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .is_some_and(|name| name.to_string_lossy().starts_with("card"))
+            })
+            .collect();
+        cards.sort();
+
+        let (mut read, mut denied, mut error) = (false, false, None);
+        for card in cards {
+            match LinuxGeneralReadout::drm_connectors_hdr(&card) {
+                Ok(true) => return Ok(true),
+                Ok(false) => read = true,
+                Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => denied = true,
+                // Cards that don't do modesetting, e.g. render-only ones or those driven by
+                // nvidia without nvidia-drm.modeset=1, reject the requests.
+                Err(e) if matches!(e.raw_os_error(), Some(libc::EOPNOTSUPP | libc::EINVAL)) => {}
+                Err(e) => error = Some(e),
+            }
+        }
+
+        if read {
+            return Ok(false);
+        }
+
+        if denied {
+            return Err(ReadoutError::PermissionDenied(String::from(
+                "Reading the properties of the displays requires access to /dev/dri.",
+            )));
+        }
+
+        match error {
+            Some(e) => Err(e.into()),
+            None => Err(ReadoutError::MetricNotAvailable),
+        }
+    }
+
+    fn has_touchscreen(&self) -> Result<bool, ReadoutError> {
+        // Bit 0x01 of the properties, set on devices whose coordinates map directly to the
+        // screen, as opposed to touchpads.
+        const INPUT_PROP_DIRECT: u64 = 1 << 0x01;
+
+        let devices = fs::read_to_string("/proc/bus/input/devices")?;
+
+        // Devices are separated by a blank line, each of their capabilities being given as
+        // a bitmask on a "B: <capability>=<mask>" line.
+        Ok(devices.split("\n\n").any(|device| {
+            let bitmask = |capability: &str| {
+                device.lines().find_map(|line| {
+                    line.strip_prefix("B: ")?
+                        .strip_prefix(capability)?
+                        .strip_prefix('=')
+                })
+            };
+
+            let is_direct = bitmask("PROP")
+                .and_then(|mask| u64::from_str_radix(mask.trim(), 16).ok())
+                .is_some_and(|mask| mask & INPUT_PROP_DIRECT != 0);
+            let has_axes = bitmask("ABS").is_some_and(|mask| mask.trim() != "0");
+
+            is_direct && has_axes
+        }))
+    }
+
+    fn display_technology(&self) -> Result<String, ReadoutError> {
+        // The first connected display stands in for the primary one, which is a notion of
+        // the display server rather than the kernel.
+        let edids = LinuxGeneralReadout::connected_edids()?;
+        let edid = edids.first().ok_or(ReadoutError::MetricNotAvailable)?;
+
+        shared::edid_display_technology(edid)
+            .map(String::from)
+            .ok_or(ReadoutError::MetricNotAvailable)
+    }
+
+    fn installed_fonts_count(&self) -> Result<usize, ReadoutError> {
+        // fontconfig prints one line per font face it knows about.
+        if extra::which("fc-list") {
+            let output = Command::new("fc-list").stderr(Stdio::null()).output()?;
+            if output.status.success() {
+                return Ok(String::from_utf8_lossy(&output.stdout).lines().count());
+            }
+        }
+
+        let mut font_dirs = vec![
+            PathBuf::from("/usr/share/fonts"),
+            PathBuf::from("/usr/local/share/fonts"),
+        ];
+
+        if let Some(data_dir) = dirs::data_local_dir() {
+            font_dirs.push(data_dir.join("fonts"));
+        }
+
+        if let Some(home) = dirs::home_dir() {
+            font_dirs.push(home.join(".fonts"));
+        }
+
+        let count = font_dirs
+            .iter()
+            .flat_map(|dir| walkdir::WalkDir::new(dir).into_iter().flatten())
+            .filter(|entry| {
+                matches!(
+                    path_extension(entry.path()),
+                    Some("ttf" | "otf" | "ttc" | "woff" | "woff2")
+                )
+            })
+            .count();
+
+        Ok(count)
+    }
+
+    fn wsl_version(&self) -> Result<u8, ReadoutError> {
+        // The kernels shipped with WSL carry "Microsoft" in their release, e.g.
+        // "4.4.0-19041-Microsoft" for WSL 1 and "5.15.90.1-microsoft-standard-WSL2" for WSL 2.
+        let release = fs::read_to_string("/proc/sys/kernel/osrelease")?.to_lowercase();
+        let version = fs::read_to_string("/proc/version")?.to_lowercase();
+
+        if !release.contains("microsoft") && !version.contains("microsoft") {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        // Early WSL 2 kernels didn't mention WSL2, but WSL 1 doesn't use a standard kernel,
+        // let alone create /run/WSL.
+        if release.contains("wsl2")
+            || release.contains("microsoft-standard")
+            || Path::new("/run/WSL").exists()
+        {
+            Ok(2)
+        } else {
+            Ok(1)
+        }
+    }
+
+    fn bootloader(&self) -> Result<String, ReadoutError> {
+        // Bootloaders implementing the Boot Loader Interface, such as systemd-boot, tell us
+        // their name and version through an EFI variable, e.g. "systemd-boot 254.1".
+        if let Ok(loader_info) =
+            fs::read("/sys/firmware/efi/efivars/LoaderInfo-4a67b082-0a4c-41cf-b6c7-440b29bb8c4f")
+        {
+            // The first four bytes hold the attributes of the variable, followed by a
+            // NUL-terminated UTF-16 string.
+            let value: Vec<u16> = loader_info
+                .get(4..)
+                .unwrap_or_default()
+                .chunks_exact(2)
+                .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+                .take_while(|&c| c != 0)
+                .collect();
+
+            let loader = String::from_utf16_lossy(&value);
+            if !loader.is_empty() {
+                return Ok(loader);
+            }
+        }
+
+        // Otherwise, we look for the files each bootloader installs on the ESP or in /boot.
+        let installed = |paths: &[&str]| paths.iter().any(|path| Path::new(path).exists());
+
+        if installed(&[
+            "/boot/loader/loader.conf",
+            "/boot/efi/loader/loader.conf",
+            "/efi/loader/loader.conf",
+        ]) {
+            return Ok(String::from("systemd-boot"));
+        }
+
+        if installed(&[
+            "/boot/EFI/refind",
+            "/boot/efi/EFI/refind",
+            "/efi/EFI/refind",
+        ]) {
+            return Ok(String::from("rEFInd"));
+        }
+
+        if installed(&["/boot/grub", "/boot/grub2"]) {
+            // "grub-install (GRUB) 2.12"
+            let version = ["grub-install", "grub2-install"]
+                .iter()
+                .find_map(|program| {
+                    let output = Command::new(program)
+                        .arg("--version")
+                        .stdout(Stdio::piped())
+                        .stderr(Stdio::null())
+                        .output()
+                        .ok()?;
+
+                    String::from_utf8_lossy(&output.stdout)
+                        .split_whitespace()
+                        .last()
+                        .map(String::from)
+                });
+
+            return match version {
+                Some(version) => Ok(format!("GRUB {version}")),
+                None => Ok(String::from("GRUB")),
+            };
+        }
+
+        Err(ReadoutError::MetricNotAvailable)
+    }
+
+    fn entropy_available(&self) -> Result<u32, ReadoutError> {
+        let entropy =
+            extra::pop_newline(fs::read_to_string("/proc/sys/kernel/random/entropy_avail")?);
+
+        entropy
+            .parse::<u32>()
+            .map_err(|e| ReadoutError::Other(format!("Could not parse the available entropy: {e}")))
+    }
+
+    fn trim_enabled(&self) -> Result<bool, ReadoutError> {
+        let (device, options) = LinuxGeneralReadout::root_mount()?;
+
+        // TRIM doesn't apply to spinning disks.
+        if LinuxGeneralReadout::is_rotational(Path::new(&device)) == Some(true) {
+            return Err(ReadoutError::NotImplemented);
+        }
+
+        // Discarding can either happen continuously, as blocks are freed, or periodically,
+        // which most distributions schedule through util-linux's fstrim.timer.
+        if LinuxGeneralReadout::mounted_with_discard(&options) {
+            return Ok(true);
+        }
+
+        Ok(
+            Path::new("/etc/systemd/system/timers.target.wants/fstrim.timer").exists()
+                || Path::new("/usr/lib/systemd/system/timers.target.wants/fstrim.timer").exists(),
+        )
+    }
+}
+
+impl LinuxGeneralReadout {
+    /// Returns the number of packages the host's package manager could upgrade, which both
+    /// [GeneralReadout::pending_updates] and [PackageReadout::available_updates] report.
+    fn package_updates() -> Result<usize, ReadoutError> {
+        if extra::which("apt-get") {
+            // Simulating an upgrade lists one "Inst" line per package that would be upgraded.
+            let output = Command::new("apt-get")
+                .args(["-s", "upgrade"])
+                .env("LC_ALL", "C")
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .output()?;
+
+            if !output.status.success() {
+                return Err(ReadoutError::Other(String::from(
+                    "apt-get failed to simulate an upgrade.",
+                )));
+            }
+
+            return Ok(String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter(|line| line.starts_with("Inst "))
+                .count());
+        }
+
+        if extra::which("dnf") {
+            // dnf exits with 100 when updates are available, and 0 when there are none.
+            let output = Command::new("dnf")
+                .args(["check-update", "--quiet"])
+                .env("LC_ALL", "C")
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .output()?;
+
+            return match output.status.code() {
+                Some(0) => Ok(0),
+                // Packages are listed one per line, before any packages they obsolete.
+                Some(100) => Ok(String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .take_while(|line| !line.starts_with("Obsoleting"))
+                    .filter(|line| line.split_whitespace().count() == 3)
+                    .count()),
+                _ => Err(ReadoutError::Other(String::from(
+                    "dnf failed to check for updates.",
+                ))),
+            };
+        }
+
+        if extra::which("pacman") {
+            return LinuxGeneralReadout::pacman_updates();
+        }
+
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// Returns the number of packages pacman could upgrade.
+    ///
+    /// `checkupdates`, from pacman-contrib, is preferred as it syncs a copy of the package
+    /// databases first; `pacman -Qu` is used otherwise, which only knows about updates the
+    /// last `pacman -Sy` has seen.
+    fn pacman_updates() -> Result<usize, ReadoutError> {
+        let checkupdates = extra::which("checkupdates");
+        let output = if checkupdates {
+            Command::new("checkupdates").output()?
+        } else {
+            Command::new("pacman").arg("-Qu").output()?
+        };
+
+        // checkupdates exits with 2 and pacman with 1 when there's nothing to upgrade,
+        // packages are otherwise listed one per line.
+        match (output.status.code(), checkupdates) {
+            (Some(0), _) => Ok(String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .count()),
+            (Some(2), true) | (Some(1), false) if output.stdout.is_empty() => Ok(0),
+            _ => Err(ReadoutError::Other(String::from(
+                "pacman failed to check for updates.",
+            ))),
+        }
+    }
+
+    /// Returns whether the given mount options discard blocks as they're freed, _e.g._
+    /// `discard` or `discard=async`, which btrfs uses by default on SSDs. The last of
+    /// `discard` and `nodiscard` wins, the same way it does for `mount`.
+    fn mounted_with_discard(options: &str) -> bool {
+        options
+            .split(',')
+            .filter_map(|option| match option {
+                "nodiscard" => Some(false),
+                "discard" => Some(true),
+                _ => option.starts_with("discard=").then_some(true),
+            })
+            .next_back()
+            .unwrap_or(false)
+    }
+
+    /// Returns whether the firmware of a Raspberry Pi is currently capping or throttling
+    /// its processor, or `None` when not running on one.
+    fn raspberry_pi_throttled() -> Option<bool> {
+        // Bits 1 to 3 of the value are set while the frequency is capped, the processor is
+        // throttled, or the soft temperature limit is active.
+        const THROTTLED: u32 = 0b1110;
+
+        // Recent kernels expose the value directly, older ones only through vcgencmd,
+        // which reports it as "throttled=0x50005".
+        let value = match fs::read_to_string("/sys/devices/platform/soc/soc:firmware/get_throttled")
+        {
+            Ok(value) => extra::pop_newline(value),
+            Err(_) if extra::which("vcgencmd") => {
+                let output = Command::new("vcgencmd")
+                    .arg("get_throttled")
+                    .stderr(Stdio::null())
+                    .output()
+                    .ok()?;
+
+                String::from_utf8(output.stdout)
+                    .ok()?
+                    .trim()
+                    .strip_prefix("throttled=")?
+                    .to_string()
+            }
+            Err(_) => return None,
+        };
+
+        let value = u32::from_str_radix(value.trim_start_matches("0x"), 16).ok()?;
+        Some(value & THROTTLED != 0)
+    }
+
+    /// Returns the device and the mount options of the root filesystem, as listed in
+    /// `/proc/mounts`.
+    fn root_mount() -> Result<(String, String), ReadoutError> {
+        let mounts = fs::read_to_string("/proc/mounts")?;
+
+        // Filesystems mounted over "/" later on hide the ones listed before them.
+        mounts
+            .lines()
+            .rev()
+            .find_map(|line| {
+                let mut fields = line.split_whitespace();
+                let device = fields.next()?;
+                let mount_point = fields.next()?;
+                let options = fields.nth(1)?;
+
+                (mount_point == "/").then(|| (device.to_owned(), options.to_owned()))
+            })
+            .ok_or(ReadoutError::MetricNotAvailable)
+    }
+
+    /// Returns the device of the filesystem the given path lives on, as listed in
+    /// `/proc/mounts`.
+    fn mount_device(path: &Path) -> Result<String, ReadoutError> {
+        let path = fs::canonicalize(path)?;
+        let mounts = fs::read_to_string("/proc/mounts")?;
+
+        // The deepest mount point the path lies under wins, and filesystems mounted over the
+        // same point later on hide the ones listed before them.
+        mounts
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let device = fields.next()?;
+                let mount_point = unescape_mount_field(fields.next()?);
+
+                path.starts_with(&mount_point)
+                    .then(|| (mount_point.len(), device.to_owned()))
+            })
+            .max_by_key(|(len, _)| *len)
+            .map(|(_, device)| device)
+            .ok_or(ReadoutError::MetricNotAvailable)
+    }
+
+    /// Returns the device the given device-mapper target is built on, if it's one.
+    fn disk_slave(device: &Path) -> Option<PathBuf> {
+        let slaves = LinuxGeneralReadout::sysfs_block(device)?.join("slaves");
+        let slave = get_entries(&slaves)?.into_iter().next()?;
+        Some(Path::new("/dev").join(slave.file_name()?))
+    }
+
+    /// Returns whether the given block device, or the disk the partition belongs to, is
+    /// a rotational one, _i.e._ a hard disk drive.
+    fn is_rotational(device: &Path) -> Option<bool> {
+        let rotational = LinuxGeneralReadout::disk_attribute(device, "queue/rotational")?;
+        Some(rotational == "1")
+    }
+
+    /// Reads one of the attributes the kernel exposes under `/sys/class/block` for the
+    /// given block device, or for the disk the partition belongs to.
+    fn disk_attribute(device: &Path, attribute: &str) -> Option<String> {
+        let block = LinuxGeneralReadout::sysfs_block(device)?;
+
+        // Partitions don't carry the attributes of the disk they're part of.
+        let path = [block.join(attribute), block.parent()?.join(attribute)]
+            .into_iter()
+            .find(|path| path.exists())?;
+
+        fs::read_to_string(path).ok().map(extra::pop_newline)
+    }
+
+    /// Returns the directory of the given block device in sysfs, _e.g._
+    /// `/sys/devices/pci0000:00/0000:00:1d.0/0000:3d:00.0/nvme/nvme0/nvme0n1/nvme0n1p2`.
+    fn sysfs_block(device: &Path) -> Option<PathBuf> {
+        let name = fs::canonicalize(device).ok()?.file_name()?.to_owned();
+        fs::canonicalize(Path::new("/sys/class/block").join(name)).ok()
+    }
+
+    /// Returns the label of the filesystem on the given block device.
+    fn filesystem_label(device: &Path) -> Option<String> {
+        let device = fs::canonicalize(device).ok()?;
+
+        // Labels are escaped the same way as mount points, e.g. "\x20" stands for a space.
+        get_entries(Path::new("/dev/disk/by-label"))?
+            .into_iter()
+            .find(|label| fs::canonicalize(label).is_ok_and(|target| target == device))
+            .and_then(|label| Some(unescape_mount_field(label.file_name()?.to_str()?)))
+    }
+
+    /// Returns the time all CPUs have spent idle, and the total time they have been running,
+    /// in clock ticks since boot, as reported by the first line of `/proc/stat`.
+    fn cpu_times() -> Result<(u64, u64), ReadoutError> {
+        let times = LinuxGeneralReadout::cpu_time_counters()?;
+
+        let idle = times[3] + times[4];
+        let total = times.iter().sum();
+
+        Ok((idle, total))
+    }
+
+    /// Returns the time all CPUs have spent in user, nice, system, idle, iowait, irq, softirq
+    /// and steal, in clock ticks since boot, as reported by the first line of `/proc/stat`.
+    fn cpu_time_counters() -> Result<[u64; 8], ReadoutError> {
+        let stat = fs::read_to_string("/proc/stat")?;
+        let times: Vec<u64> = stat
+            .lines()
+            .next()
+            .and_then(|line| line.strip_prefix("cpu "))
+            .ok_or(ReadoutError::MetricNotAvailable)?
+            .split_whitespace()
+            .filter_map(|time| time.parse().ok())
+            .collect();
+
+        // The guest times that follow steal are already accounted for in user and nice.
+        times
+            .get(..8)
+            .and_then(|times| times.try_into().ok())
+            .ok_or(ReadoutError::MetricNotAvailable)
+    }
+
+    /// Returns the _EDID_ of every display connected to a DRM connector, ordered by the
+    /// name of the connector.
+    fn connected_edids() -> Result<Vec<Vec<u8>>, ReadoutError> {
+        let mut entries = get_entries(Path::new("/sys/class/drm")).ok_or(ReadoutError::Other(
+            String::from("Could not read the connectors listed in /sys/class/drm"),
+        ))?;
+        entries.sort();
+
+        // Connectors are named after the card they belong to, e.g. "card0-HDMI-A-1", and
+        // hold an empty EDID when nothing is plugged into them.
+        Ok(entries
+            .into_iter()
+            .filter(|entry| {
+                fs::read_to_string(entry.join("status")).is_ok_and(|s| s.trim() == "connected")
+            })
+            .filter_map(|entry| fs::read(entry.join("edid")).ok())
+            .filter(|edid| !edid.is_empty())
+            .collect())
+    }
+
+    /// Returns the monitor name held by the display descriptors of the given _EDID_.
+    fn edid_monitor_name(edid: &[u8]) -> Option<String> {
+        const HEADER: [u8; 8] = [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+        const MONITOR_NAME: u8 = 0xFC;
+
+        if edid.len() < 128 || edid[..8] != HEADER {
+            return None;
+        }
+
+        // The base block holds four 18-byte descriptors, display descriptors start with
+        // three zero bytes followed by their type, and carry up to 13 bytes of text that
+        // end with a line feed and are padded with spaces.
+        edid[54..126]
+            .chunks_exact(18)
+            .find(|d| d[..3] == [0, 0, 0] && d[3] == MONITOR_NAME)
+            .map(|d| {
+                let text = &d[5..];
+                let len = text.iter().position(|&b| b == b'\n').unwrap_or(text.len());
+                String::from_utf8_lossy(&text[..len]).trim().to_string()
+            })
+            .filter(|name| !name.is_empty())
+    }
+
+    /// Returns whether any connector of the given DRM card is sending _HDR_ metadata to its
+    /// display, which compositors only do while driving it in HDR mode.
+    fn drm_connectors_hdr(card: &Path) -> std::io::Result<bool> {
+        use drm_ffi::*;
+        use std::ffi::{c_ulong, c_void, CStr};
+        use std::os::unix::io::AsRawFd;
+
+        let file = File::open(card)?;
+        let fd = file.as_raw_fd();
+        let ioctl = |request: c_ulong, arg: *mut c_void| match unsafe {
+            libc::ioctl(fd, request as _, arg)
+        } {
+            0 => Ok(()),
+            _ => Err(std::io::Error::last_os_error()),
+        };
+
+        // Both requests are issued twice, first to learn the number of items to make room
+        // for, then to have them filled in.
+        let mut resources = drm_mode_card_res::default();
+        ioctl(
+            DRM_IOCTL_MODE_GETRESOURCES,
+            &mut resources as *mut _ as *mut c_void,
+        )?;
+
+        let mut connectors = vec![0u32; resources.count_connectors as usize];
+        resources = drm_mode_card_res {
+            connector_id_ptr: connectors.as_mut_ptr() as u64,
+            count_connectors: connectors.len() as u32,
+            ..Default::default()
+        };
+        ioctl(
+            DRM_IOCTL_MODE_GETRESOURCES,
+            &mut resources as *mut _ as *mut c_void,
+        )?;
+        connectors.truncate(resources.count_connectors as usize);
+
+        for connector in connectors {
+            let mut properties = drm_mode_obj_get_properties {
+                obj_id: connector,
+                obj_type: DRM_MODE_OBJECT_CONNECTOR,
+                ..Default::default()
+            };
+            ioctl(
+                DRM_IOCTL_MODE_OBJ_GETPROPERTIES,
+                &mut properties as *mut _ as *mut c_void,
+            )?;
+
+            let count = properties.count_props as usize;
+            let (mut ids, mut values) = (vec![0u32; count], vec![0u64; count]);
+            properties.props_ptr = ids.as_mut_ptr() as u64;
+            properties.prop_values_ptr = values.as_mut_ptr() as u64;
+            ioctl(
+                DRM_IOCTL_MODE_OBJ_GETPROPERTIES,
+                &mut properties as *mut _ as *mut c_void,
+            )?;
+
+            let count = count.min(properties.count_props as usize);
+            for (&id, &value) in ids.iter().zip(values.iter()).take(count) {
+                let mut property = drm_mode_get_property {
+                    prop_id: id,
+                    ..Default::default()
+                };
+                ioctl(
+                    DRM_IOCTL_MODE_GETPROPERTY,
+                    &mut property as *mut _ as *mut c_void,
+                )?;
+
+                let name = unsafe { CStr::from_ptr(property.name.as_ptr()) };
+
+                // The value of the property is the identifier of the blob holding the
+                // metadata, or 0 when there is none.
+                if name.to_bytes() == b"HDR_OUTPUT_METADATA" && value != 0 {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Returns the resolution and refresh rate of the preferred timing of the given _EDID_,
+    /// which is held by its first detailed timing descriptor.
+    fn edid_preferred_timing(edid: &[u8]) -> Option<((u32, u32), u32)> {
+        if edid.len() < 128 {
+            return None;
+        }
+
+        // The pixel clock is given in units of 10 kHz, and the active and blanking pixel
+        // counts are made up of 8 lower bits, and 4 upper bits shared with one another.
+        let d = &edid[54..72];
+        let clock = u16::from_le_bytes([d[0], d[1]]) as u64 * 10_000;
+        if clock == 0 {
+            return None;
+        }
+
+        let h_active = d[2] as u32 | (d[4] as u32 & 0xF0) << 4;
+        let h_blank = d[3] as u32 | (d[4] as u32 & 0x0F) << 8;
+        let v_active = d[5] as u32 | (d[7] as u32 & 0xF0) << 4;
+        let v_blank = d[6] as u32 | (d[7] as u32 & 0x0F) << 8;
+
+        let total = (h_active + h_blank) as u64 * (v_active + v_blank) as u64;
+        if total == 0 {
+            return None;
+        }
+
+        let refresh_rate = (clock as f64 / total as f64).round() as u32;
+        Some(((h_active, v_active), refresh_rate))
+    }
+
+    /// Parses the outputs listed by `xrandr --query` that are connected and enabled, _e.g._
+    ///
+    /// ```text
+    /// HDMI-1 connected primary 1920x1080+0+0 (normal left inverted right) 527mm x 296mm
+    ///    1920x1080     60.00*+  50.00    59.94
+    /// ```
+    fn parse_xrandr(output: &str, color_depth: u32) -> Vec<MonitorInfo> {
+        let mut monitors: Vec<MonitorInfo> = vec![];
+        let mut current: Option<MonitorInfo> = None;
+
+        for line in output.lines() {
+            // The modes of an output are indented, the one in use being marked with an
+            // asterisk.
+            if line.starts_with(char::is_whitespace) {
+                let Some(monitor) = current.as_mut() else {
+                    continue;
+                };
+
+                let rate = line
+                    .split_whitespace()
+                    .find(|rate| rate.contains('*'))
+                    .and_then(|rate| rate.trim_end_matches(['*', '+']).parse::<f32>().ok());
+
+                if let Some(rate) = rate {
+                    monitor.refresh_rate = rate.round() as u32;
+                }
+
+                continue;
+            }
+
+            monitors.extend(current.take());
+
+            let mut fields = line.split_whitespace();
+            let (Some(name), Some("connected")) = (fields.next(), fields.next()) else {
+                continue;
+            };
+
+            // Outputs that are connected but disabled have no geometry, e.g. 1920x1080+0+0.
+            let mut is_primary = false;
+            let resolution = fields.find_map(|field| {
+                is_primary |= field == "primary";
+
+                let (width, height) = field.split('+').next()?.split_once('x')?;
+                Some((width.parse::<u32>().ok()?, height.parse::<u32>().ok()?))
+            });
+
+            current = resolution.map(|resolution| MonitorInfo {
+                name: name.to_string(),
+                resolution,
+                refresh_rate: 0,
+                is_primary,
+                color_depth,
+            });
+        }
+
+        monitors.extend(current);
+        monitors
+    }
+
+    /// Returns the graphics cards registered under `/sys/class/drm`, leaving out the
+    /// connectors (e.g. `card0-HDMI-A-1`) that are listed alongside them.
+    #[cfg(feature = "full")]
+    fn drm_cards() -> Vec<PathBuf> {
+        let Some(entries) = get_entries(Path::new("/sys/class/drm")) else {
+            return vec![];
+        };
+
+        let mut cards: Vec<PathBuf> = entries
+            .into_iter()
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .and_then(|name| name.strip_prefix("card"))
+                    .is_some_and(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()))
+            })
+            .collect();
+
+        cards.sort();
+        cards
+    }
+
+    /// Returns the name and amount of memory, in megabytes, of each GPU listed by
+    /// `nvidia-smi`, or nothing if it isn't installed.
+    #[cfg(feature = "full")]
+    fn nvidia_smi_vram() -> Vec<(String, u64)> {
+        if !extra::which("nvidia-smi") {
+            return vec![];
+        }
+
+        let Ok(output) = Command::new("nvidia-smi")
+            .args([
+                "--query-gpu=name,memory.total",
+                "--format=csv,noheader,nounits",
+            ])
+            .stderr(Stdio::null())
+            .output()
+        else {
+            return vec![];
+        };
+
+        // Each line reads "<name>, <memory>", the memory being given in MiB.
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| {
+                let (name, memory) = line.rsplit_once(',')?;
+                Some((name.trim().to_string(), memory.trim().parse::<u64>().ok()?))
+            })
+            .collect()
+    }
+
+    /// Returns the device name of the given graphics card, falling back to the name of the
+    /// card itself (e.g. `card0`) if it can't be found in the PCI ID database.
+    #[cfg(feature = "full")]
+    fn gpu_name(card: &Path, db: Option<&Database>) -> String {
+        if let Some(db) = db {
+            if let Some(name) = PciDevice::from_path(&card.join("device"))
+                .and_then(|device| device.get_device_name(db))
+            {
+                return name;
+            }
+        }
+
+        card.file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+}
+
+impl MemoryReadout for LinuxMemoryReadout {
+    fn new() -> Self {
+        LinuxMemoryReadout {
+            sysinfo: sysinfo::new(),
+        }
+    }
+
+    fn total(&self) -> Result<u64, ReadoutError> {
+        let mut info = self.sysinfo;
+        let info_ptr: *mut sysinfo = &mut info;
+        let ret = unsafe { sysinfo(info_ptr) };
+        if ret != -1 {
+            Ok(info.totalram as u64 * info.mem_unit as u64 / 1024)
+        } else {
+            Err(ReadoutError::Other(
+                "Something went wrong during the initialization of the sysinfo struct.".to_string(),
+            ))
+        }
+    }
+
+    fn free(&self) -> Result<u64, ReadoutError> {
+        let mut info = self.sysinfo;
+        let info_ptr: *mut sysinfo = &mut info;
+        let ret = unsafe { sysinfo(info_ptr) };
+        if ret != -1 {
+            Ok(info.freeram as u64 * info.mem_unit as u64 / 1024)
+        } else {
+            Err(ReadoutError::Other(
+                "Something went wrong during the initialization of the sysinfo struct.".to_string(),
+            ))
+        }
+    }
+
+    fn buffers(&self) -> Result<u64, ReadoutError> {
+        let mut info = self.sysinfo;
+        let info_ptr: *mut sysinfo = &mut info;
+        let ret = unsafe { sysinfo(info_ptr) };
+        if ret != -1 {
+            Ok(info.bufferram as u64 * info.mem_unit as u64 / 1024)
+        } else {
+            Err(ReadoutError::Other(
+                "Something went wrong during the initialization of the sysinfo struct.".to_string(),
+            ))
+        }
+    }
+
+    fn cached(&self) -> Result<u64, ReadoutError> {
+        Ok(shared::get_meminfo_value("Cached"))
+    }
+
+    fn reclaimable(&self) -> Result<u64, ReadoutError> {
+        Ok(shared::get_meminfo_value("SReclaimable"))
+    }
 
     fn used(&self) -> Result<u64, ReadoutError> {
         let total = self.total().unwrap();
@@ -641,6 +2601,151 @@ impl MemoryReadout for LinuxMemoryReadout {
             available => Ok(total - available),
         }
     }
+
+    fn swap_total(&self) -> Result<u64, ReadoutError> {
+        let mut info = self.sysinfo;
+        let info_ptr: *mut sysinfo = &mut info;
+        let ret = unsafe { sysinfo(info_ptr) };
+        if ret != -1 {
+            Ok(info.totalswap as u64 * info.mem_unit as u64 / 1024)
+        } else {
+            Err(ReadoutError::Other(
+                "Something went wrong during the initialization of the sysinfo struct.".to_string(),
+            ))
+        }
+    }
+
+    fn swap_used(&self) -> Result<u64, ReadoutError> {
+        let mut info = self.sysinfo;
+        let info_ptr: *mut sysinfo = &mut info;
+        let ret = unsafe { sysinfo(info_ptr) };
+        if ret != -1 {
+            Ok((info.totalswap as u64 - info.freeswap as u64) * info.mem_unit as u64 / 1024)
+        } else {
+            Err(ReadoutError::Other(
+                "Something went wrong during the initialization of the sysinfo struct.".to_string(),
+            ))
+        }
+    }
+
+    fn swap_io(&self) -> Result<(u64, u64), ReadoutError> {
+        let vmstat = fs::read_to_string("/proc/vmstat")?;
+        let counter = |name: &str| {
+            vmstat
+                .lines()
+                .find_map(|line| line.strip_prefix(name)?.strip_prefix(' '))
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+
+        match (counter("pswpin"), counter("pswpout")) {
+            (Some(swapped_in), Some(swapped_out)) => Ok((swapped_in, swapped_out)),
+            _ => Err(ReadoutError::MetricNotAvailable),
+        }
+    }
+
+    fn memory_limit(&self) -> Result<u64, ReadoutError> {
+        let limit = LinuxMemoryReadout::cgroup_limit("memory.max", "memory.limit_in_bytes")?;
+
+        // Unlimited cgroup v1 groups report a limit close to the largest 64-bit value.
+        if limit >= self.total()? {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        Ok(limit)
+    }
+
+    fn swap_limit(&self) -> Result<u64, ReadoutError> {
+        if let Ok(limit) = LinuxMemoryReadout::cgroup_limit("memory.swap.max", "") {
+            return Ok(limit);
+        }
+
+        // cgroup v1 only limits memory and swap together.
+        let limit = LinuxMemoryReadout::cgroup_limit("", "memory.memsw.limit_in_bytes")?;
+        let swap = limit.saturating_sub(self.memory_limit()?);
+
+        if swap >= self.swap_total()? {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        Ok(swap)
+    }
+
+    fn vm_tunable(&self, name: &str) -> Result<i64, ReadoutError> {
+        // Only accept the name of a file directly inside /proc/sys/vm.
+        if name.is_empty() || name.contains('/') || name.contains("..") {
+            return Err(ReadoutError::Other(format!(
+                "\"{name}\" is not a valid VM tunable."
+            )));
+        }
+
+        let content = fs::read_to_string(Path::new("/proc/sys/vm").join(name))?;
+        extra::pop_newline(content)
+            .parse::<i64>()
+            .map_err(|e| ReadoutError::Other(format!("Could not parse {name}: {e}")))
+    }
+}
+
+impl LinuxMemoryReadout {
+    /// Returns the limit, in kilobytes, set in the given file of the memory controller of the
+    /// current process' control group; `v2` naming the file under cgroup v2's unified
+    /// hierarchy and `v1` the one under cgroup v1's memory hierarchy. An empty name skips a
+    /// hierarchy.
+    fn cgroup_limit(v2: &str, v1: &str) -> Result<u64, ReadoutError> {
+        let cgroups = fs::read_to_string("/proc/self/cgroup")?;
+
+        let limit = LinuxMemoryReadout::cgroup_files(&cgroups, v2, v1)
+            .iter()
+            .find_map(|file| fs::read_to_string(file).ok())
+            .ok_or(ReadoutError::MetricNotAvailable)?;
+
+        LinuxMemoryReadout::parse_cgroup_limit(&limit)
+    }
+
+    /// Returns the files the given setting of the memory controller may be found in, in
+    /// order of preference, for the control groups listed in `cgroups`, the content of
+    /// `/proc/<pid>/cgroup`.
+    fn cgroup_files(cgroups: &str, v2: &str, v1: &str) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+
+        // Each line reads "<id>:<controllers>:<path>", where the controllers are left empty
+        // for the unified hierarchy.
+        for line in cgroups.lines() {
+            let mut fields = line.splitn(3, ':').skip(1);
+            let (Some(controllers), Some(path)) = (fields.next(), fields.next()) else {
+                continue;
+            };
+
+            let (root, file) = if controllers.is_empty() {
+                (Path::new("/sys/fs/cgroup"), v2)
+            } else if controllers.split(',').any(|c| c == "memory") {
+                (Path::new("/sys/fs/cgroup/memory"), v1)
+            } else {
+                continue;
+            };
+
+            if file.is_empty() {
+                continue;
+            }
+
+            // Containers without their own cgroup namespace see the host's path to their
+            // group, yet get it mounted as the root of the hierarchy.
+            files.push(root.join(path.trim_start_matches('/')).join(file));
+            files.push(root.join(file));
+        }
+
+        files.dedup();
+        files
+    }
+
+    /// Parses the content of a cgroup limit file, given in bytes, to kilobytes.
+    fn parse_cgroup_limit(limit: &str) -> Result<u64, ReadoutError> {
+        match limit.trim() {
+            "max" => Err(ReadoutError::MetricNotAvailable),
+            limit => limit.parse::<u64>().map(|bytes| bytes / 1024).map_err(|e| {
+                ReadoutError::Other(format!("Could not parse the cgroup's limit: {e}"))
+            }),
+        }
+    }
 }
 
 impl ProductReadout for LinuxProductReadout {
@@ -665,8 +2770,45 @@ impl ProductReadout for LinuxProductReadout {
             "/sys/class/dmi/id/product_name",
         )?))
     }
+
+    fn serial_number(&self) -> Result<String, ReadoutError> {
+        match fs::read_to_string("/sys/class/dmi/id/product_serial") {
+            Ok(serial) => Ok(extra::pop_newline(serial)),
+            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+                Err(ReadoutError::PermissionDenied(String::from(
+                    "Reading the serial number of this machine requires root privileges.",
+                )))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn form_factor(&self) -> Result<String, ReadoutError> {
+        let chassis_type =
+            extra::pop_newline(fs::read_to_string("/sys/class/dmi/id/chassis_type")?);
+
+        // These are the chassis types defined by the SMBIOS specification, "Other" and
+        // "Unknown" being left out, along with the ones that don't describe a computer.
+        let form_factor = match chassis_type.parse::<u8>() {
+            Ok(3..=7 | 13 | 15 | 16 | 24 | 34..=36) => "Desktop",
+            Ok(8..=10 | 14 | 31 | 32) => "Laptop",
+            Ok(30) => "Tablet",
+            Ok(11) => "Handheld",
+            Ok(17 | 23 | 25 | 28 | 29) => "Server",
+            _ => return Err(ReadoutError::MetricNotAvailable),
+        };
+
+        Ok(form_factor.to_string())
+    }
+
+    fn bios_date(&self) -> Result<String, ReadoutError> {
+        Ok(extra::pop_newline(fs::read_to_string(
+            "/sys/class/dmi/id/bios_date",
+        )?))
+    }
 }
 
+#[cfg(feature = "full")]
 impl PackageReadout for LinuxPackageReadout {
     fn new() -> Self {
         LinuxPackageReadout
@@ -702,6 +2844,18 @@ impl PackageReadout for LinuxPackageReadout {
             packages.push((PackageManager::Cargo, c));
         }
 
+        if let Some(c) = LinuxPackageReadout::count_pnpm() {
+            packages.push((PackageManager::Pnpm, c));
+        }
+
+        if let Some(c) = LinuxPackageReadout::count_bun() {
+            packages.push((PackageManager::Bun, c));
+        }
+
+        if let Some(c) = LinuxPackageReadout::count_asdf() {
+            packages.push((PackageManager::Asdf, c));
+        }
+
         if let Some(c) = LinuxPackageReadout::count_xbps() {
             packages.push((PackageManager::Xbps, c));
         }
@@ -714,10 +2868,14 @@ impl PackageReadout for LinuxPackageReadout {
             packages.push((PackageManager::Apk, c));
         }
 
-        if let Some(c) = LinuxPackageReadout::count_flatpak(&home) {
+        if let Some(c) = LinuxPackageReadout::count_flatpak_apps(&home) {
             packages.push((PackageManager::Flatpak, c));
         }
 
+        if let Some(c) = LinuxPackageReadout::count_flatpak_runtimes(&home) {
+            packages.push((PackageManager::FlatpakRuntime, c));
+        }
+
         if let Some(c) = LinuxPackageReadout::count_snap() {
             packages.push((PackageManager::Snap, c));
         }
@@ -732,8 +2890,13 @@ impl PackageReadout for LinuxPackageReadout {
 
         packages
     }
+
+    fn available_updates(&self) -> Result<usize, ReadoutError> {
+        LinuxGeneralReadout::package_updates()
+    }
 }
 
+#[cfg(feature = "full")]
 impl LinuxPackageReadout {
     /// Returns the number of installed packages for systems
     /// that utilize `rpm` as their package manager.
@@ -887,32 +3050,59 @@ impl LinuxPackageReadout {
         shared::count_cargo()
     }
 
-    /// Returns the number of installed packages for systems
-    /// that have `flatpak` installed.
-    fn count_flatpak(home: &Path) -> Option<usize> {
+    /// Returns the number of packages installed globally through `pnpm`.
+    fn count_pnpm() -> Option<usize> {
+        shared::count_pnpm()
+    }
+
+    /// Returns the number of packages installed globally through `bun`.
+    fn count_bun() -> Option<usize> {
+        shared::count_bun()
+    }
+
+    /// Returns the number of tools installed through `asdf`.
+    fn count_asdf() -> Option<usize> {
+        shared::count_asdf()
+    }
+
+    /// Returns the number of applications installed through `flatpak`,
+    /// system-wide and for the current user.
+    fn count_flatpak_apps(home: &Path) -> Option<usize> {
+        LinuxPackageReadout::count_flatpak_refs(home, "app")
+    }
+
+    /// Returns the number of runtimes installed through `flatpak`,
+    /// system-wide and for the current user.
+    ///
+    /// Runtimes are what applications are built upon, they're counted
+    /// apart from the applications themselves the same way
+    /// `flatpak list --runtime` lists them apart from `flatpak list --app`.
+    fn count_flatpak_runtimes(home: &Path) -> Option<usize> {
+        LinuxPackageReadout::count_flatpak_refs(home, "runtime")
+    }
+
+    /// Returns the number of `flatpak` refs of the given kind, _i.e._
+    /// `app` or `runtime`, leaving out locale and debug extensions.
+    fn count_flatpak_refs(home: &Path, kind: &str) -> Option<usize> {
         let mut total: usize = 0;
         let filter = Regex::new(r".*\.(Locale|Debug)").unwrap();
         for install in [
             Path::new("/var/lib/flatpak"),
             &home.join(".local/share/flatpak"),
         ] {
-            if install.exists() {
-                for dir in ["app", "runtime"] {
-                    let pkgdir = install.join(dir);
-                    if pkgdir.exists() {
-                        for package in walkdir::WalkDir::new(&pkgdir)
-                            .min_depth(1)
-                            .max_depth(1)
-                            .into_iter()
-                            .filter_entry(|e| !filter.is_match(&e.path().to_string_lossy()))
-                        {
-                            total += walkdir::WalkDir::new(package.ok()?.path())
-                                .min_depth(2)
-                                .max_depth(2)
-                                .into_iter()
-                                .count();
-                        }
-                    }
+            let pkgdir = install.join(kind);
+            if pkgdir.exists() {
+                for package in walkdir::WalkDir::new(&pkgdir)
+                    .min_depth(1)
+                    .max_depth(1)
+                    .into_iter()
+                    .filter_entry(|e| !filter.is_match(&e.path().to_string_lossy()))
+                {
+                    total += walkdir::WalkDir::new(package.ok()?.path())
+                        .min_depth(2)
+                        .max_depth(2)
+                        .into_iter()
+                        .count();
                 }
             }
         }
@@ -942,7 +3132,7 @@ impl LinuxPackageReadout {
     /// Returns the number of installed packages for systems
     /// that utilize `nix` as their package manager.
     fn count_nix() -> Option<usize> {
-        return 'sqlite: {
+        'sqlite: {
             let db = "/nix/var/nix/db/db.sqlite";
             if !Path::new(db).is_file() {
                 break 'sqlite None;
@@ -969,6 +3159,347 @@ impl LinuxPackageReadout {
             }
 
             None
+        }
+    }
+}
+
+impl ProcessReadout for LinuxProcessReadout {
+    fn new() -> Self {
+        LinuxProcessReadout
+    }
+
+    fn top_processes(&self, n: usize, by: SortKey) -> Result<Vec<ProcessInfo>, ReadoutError> {
+        let before = LinuxProcessReadout::cpu_times()?;
+        std::thread::sleep(shared::PROCESS_SAMPLING_INTERVAL);
+        let after = LinuxProcessReadout::cpu_times()?;
+
+        let ticks_per_second = unsafe { libc::sysconf(libc::_SC_CLK_TCK) } as f32;
+        let elapsed_ticks = ticks_per_second * shared::PROCESS_SAMPLING_INTERVAL.as_secs_f32();
+
+        let processes = after
+            .into_iter()
+            .map(|(pid, (name, ticks))| {
+                // Processes spawned in between both samples are measured from their start.
+                let previous = before.get(&pid).map_or(0, |(_, ticks)| *ticks);
+
+                ProcessInfo {
+                    pid,
+                    name,
+                    cpu_usage: ticks.saturating_sub(previous) as f32 / elapsed_ticks * 100.0,
+                    rss: LinuxProcessReadout::resident_set_size(pid).unwrap_or(0),
+                }
+            })
+            .collect();
+
+        Ok(shared::top_processes(processes, n, by))
+    }
+
+    fn process_memory(&self) -> Result<u64, ReadoutError> {
+        LinuxProcessReadout::resident_set_size(std::process::id())
+            .map(|rss| rss * 1024)
+            .ok_or(ReadoutError::MetricNotAvailable)
+    }
+}
+
+impl LinuxProcessReadout {
+    /// Returns the name and the CPU time, in clock ticks, spent by every running process
+    /// so far, keyed by their PID.
+    fn cpu_times() -> Result<HashMap<u32, (String, u64)>, ReadoutError> {
+        let mut times = HashMap::new();
+
+        for entry in read_dir("/proc")?.map_while(Result::ok) {
+            let pid = match entry.file_name().to_string_lossy().parse::<u32>() {
+                Ok(pid) => pid,
+                _ => continue,
+            };
+
+            // The process may have exited since the directory was listed.
+            let stat = match fs::read_to_string(entry.path().join("stat")) {
+                Ok(stat) => stat,
+                _ => continue,
+            };
+
+            // The executable's name is enclosed in parentheses and may itself contain spaces
+            // or parentheses, hence why we look for the last closing one.
+            let (head, tail) = match stat.rsplit_once(')') {
+                Some(parts) => parts,
+                _ => continue,
+            };
+
+            let name = match head.split_once('(') {
+                Some((_, name)) => name.to_owned(),
+                _ => continue,
+            };
+
+            // utime and stime are the 14th and 15th fields of the file.
+            let mut fields = tail.split_whitespace().skip(11);
+            let utime = fields.next().and_then(|f| f.parse::<u64>().ok());
+            let stime = fields.next().and_then(|f| f.parse::<u64>().ok());
+
+            if let (Some(utime), Some(stime)) = (utime, stime) {
+                times.insert(pid, (name, utime + stime));
+            }
+        }
+
+        Ok(times)
+    }
+
+    /// Returns the resident set size of the given process in kilobytes.
+    fn resident_set_size(pid: u32) -> Option<u64> {
+        let status = fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+
+        status
+            .lines()
+            .find_map(|line| line.strip_prefix("VmRSS:"))
+            .and_then(|rss| rss.trim().trim_end_matches("kB").trim().parse().ok())
+    }
+}
+
+/// Reverts the escaping applied to the fields of `/proc/mounts`, where characters such as
+/// spaces are written as octal escape sequences, _e.g._ `\040`, and to the names found
+/// under `/dev/disk`, where they're written as hexadecimal ones, _e.g._ `\x20`.
+fn unescape_mount_field(field: &str) -> String {
+    let mut bytes = Vec::with_capacity(field.len());
+    let mut rest = field.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        let escaped = match tail {
+            [b'x', a, b, ..] if byte == b'\\' => std::str::from_utf8(&[*a, *b])
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+            [a, b, c, ..] if byte == b'\\' => std::str::from_utf8(&[*a, *b, *c])
+                .ok()
+                .and_then(|oct| u8::from_str_radix(oct, 8).ok()),
+            _ => None,
         };
+
+        match escaped {
+            Some(escaped) => {
+                bytes.push(escaped);
+                rest = &tail[3..];
+            }
+            None => {
+                bytes.push(byte);
+                rest = tail;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mounted_with_discard() {
+        assert!(LinuxGeneralReadout::mounted_with_discard(
+            "rw,noatime,discard"
+        ));
+        assert!(LinuxGeneralReadout::mounted_with_discard(
+            "rw,relatime,ssd,discard=async,space_cache=v2,subvolid=256,subvol=/@"
+        ));
+        assert!(!LinuxGeneralReadout::mounted_with_discard(
+            "rw,relatime,ssd,nodiscard,space_cache=v2"
+        ));
+        assert!(!LinuxGeneralReadout::mounted_with_discard(
+            "rw,discard,nodiscard"
+        ));
+        assert!(!LinuxGeneralReadout::mounted_with_discard(
+            "rw,relatime,errors=remount-ro"
+        ));
+    }
+
+    #[test]
+    fn test_release_from() {
+        assert_eq!(
+            LinuxKernelReadout::release_from("6.8.0-31-generic\n").unwrap(),
+            "6.8.0-31-generic"
+        );
+        assert_eq!(
+            LinuxKernelReadout::release_from("5.15.146.1-microsoft-standard-WSL2").unwrap(),
+            "5.15.146.1-microsoft-standard-WSL2"
+        );
+        assert_eq!(
+            LinuxKernelReadout::release_from("6.8-rc1").unwrap(),
+            "6.8-rc1"
+        );
+        assert!(LinuxKernelReadout::release_from("").is_err());
+    }
+
+    #[test]
+    fn test_cgroup_files_v2() {
+        // Inside a container with its own cgroup namespace, the group is the root.
+        assert_eq!(
+            LinuxMemoryReadout::cgroup_files("0::/\n", "memory.max", "memory.limit_in_bytes"),
+            vec![PathBuf::from("/sys/fs/cgroup/memory.max")]
+        );
+
+        assert_eq!(
+            LinuxMemoryReadout::cgroup_files(
+                "0::/system.slice/docker-4f1c.scope\n",
+                "memory.max",
+                "memory.limit_in_bytes"
+            ),
+            vec![
+                PathBuf::from("/sys/fs/cgroup/system.slice/docker-4f1c.scope/memory.max"),
+                PathBuf::from("/sys/fs/cgroup/memory.max"),
+            ]
+        );
+
+        // cgroup v2 has no file for the setting.
+        assert!(
+            LinuxMemoryReadout::cgroup_files("0::/\n", "", "memory.memsw.limit_in_bytes")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_cgroup_files_v1() {
+        let cgroups = "\
+12:pids:/docker/4f1c
+11:memory:/docker/4f1c
+4:cpu,cpuacct:/docker/4f1c
+1:name=systemd:/docker/4f1c
+";
+
+        assert_eq!(
+            LinuxMemoryReadout::cgroup_files(cgroups, "memory.max", "memory.limit_in_bytes"),
+            vec![
+                PathBuf::from("/sys/fs/cgroup/memory/docker/4f1c/memory.limit_in_bytes"),
+                PathBuf::from("/sys/fs/cgroup/memory/memory.limit_in_bytes"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cgroup_files_hybrid() {
+        // The memory controller stays on cgroup v1, alongside an empty unified hierarchy.
+        let cgroups = "\
+9:memory:/user.slice/user-1000.slice
+1:name=systemd:/user.slice/user-1000.slice/session-2.scope
+0::/user.slice/user-1000.slice/session-2.scope
+";
+
+        assert_eq!(
+            LinuxMemoryReadout::cgroup_files(cgroups, "memory.max", "memory.limit_in_bytes"),
+            vec![
+                PathBuf::from(
+                    "/sys/fs/cgroup/memory/user.slice/user-1000.slice/memory.limit_in_bytes"
+                ),
+                PathBuf::from("/sys/fs/cgroup/memory/memory.limit_in_bytes"),
+                PathBuf::from(
+                    "/sys/fs/cgroup/user.slice/user-1000.slice/session-2.scope/memory.max"
+                ),
+                PathBuf::from("/sys/fs/cgroup/memory.max"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_cgroup_limit() {
+        assert_eq!(
+            LinuxMemoryReadout::parse_cgroup_limit("2147483648\n").unwrap(),
+            2097152
+        );
+        assert!(matches!(
+            LinuxMemoryReadout::parse_cgroup_limit("max\n"),
+            Err(ReadoutError::MetricNotAvailable)
+        ));
+        assert!(matches!(
+            LinuxMemoryReadout::parse_cgroup_limit(""),
+            Err(ReadoutError::Other(_))
+        ));
+    }
+
+    /// Returns an _EDID_ whose first detailed timing descriptor starts with `timing`.
+    fn edid(timing: [u8; 8]) -> Vec<u8> {
+        let mut edid = vec![0; 128];
+        edid[54..62].copy_from_slice(&timing);
+        edid
+    }
+
+    #[test]
+    fn test_edid_preferred_timing() {
+        // 1920x1080 at 148.5 MHz, with 280 and 45 blanking pixels.
+        assert_eq!(
+            LinuxGeneralReadout::edid_preferred_timing(&edid([
+                0x02, 0x3A, 0x80, 0x18, 0x71, 0x38, 0x2D, 0x40
+            ])),
+            Some(((1920, 1080), 60))
+        );
+
+        // 3840x2160 at 533.25 MHz, whose active pixel counts need their upper bits.
+        assert_eq!(
+            LinuxGeneralReadout::edid_preferred_timing(&edid([
+                0x4D, 0xD0, 0x00, 0xA0, 0xF0, 0x70, 0x3E, 0x80
+            ])),
+            Some(((3840, 2160), 60))
+        );
+    }
+
+    #[test]
+    fn test_edid_preferred_timing_invalid() {
+        // A pixel clock of zero means the descriptor doesn't hold a timing.
+        assert_eq!(
+            LinuxGeneralReadout::edid_preferred_timing(&edid([0; 8])),
+            None
+        );
+        assert_eq!(
+            LinuxGeneralReadout::edid_preferred_timing(&edid([0x02, 0x3A, 0, 0, 0, 0, 0, 0])),
+            None
+        );
+        assert_eq!(LinuxGeneralReadout::edid_preferred_timing(&[0; 64]), None);
+    }
+
+    #[test]
+    fn test_parse_xrandr() {
+        let output = "\
+Screen 0: minimum 320 x 200, current 4480 x 1440, maximum 16384 x 16384
+eDP-1 connected primary 1920x1080+0+360 (normal left inverted right x axis y axis) 344mm x 194mm
+   1920x1080     60.02*+  59.93    48.00
+   1680x1050     59.88
+HDMI-1 connected 2560x1440+1920+0 (normal left inverted right x axis y axis) 597mm x 336mm
+   2560x1440     59.95 +  143.91*
+   1920x1080     60.00    50.00
+DP-1 disconnected (normal left inverted right x axis y axis)
+DP-2 connected (normal left inverted right x axis y axis)
+   1920x1080     60.00 +
+";
+
+        assert_eq!(
+            LinuxGeneralReadout::parse_xrandr(output, 24),
+            vec![
+                MonitorInfo {
+                    name: String::from("eDP-1"),
+                    resolution: (1920, 1080),
+                    refresh_rate: 60,
+                    is_primary: true,
+                    color_depth: 24,
+                },
+                MonitorInfo {
+                    name: String::from("HDMI-1"),
+                    resolution: (2560, 1440),
+                    refresh_rate: 144,
+                    is_primary: false,
+                    color_depth: 24,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_xrandr_without_monitors() {
+        assert!(LinuxGeneralReadout::parse_xrandr("", 24).is_empty());
+
+        // Only disabled outputs, whose modes don't have any marked as in use.
+        let output = "\
+Screen 0: minimum 320 x 200, current 1024 x 768, maximum 16384 x 16384
+Virtual-1 connected primary (normal left inverted right x axis y axis)
+   1024x768      60.00 +
+Virtual-2 disconnected (normal left inverted right x axis y axis)
+";
+        assert!(LinuxGeneralReadout::parse_xrandr(output, 24).is_empty());
     }
 }
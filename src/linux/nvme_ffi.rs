@@ -0,0 +1,33 @@
+use std::os::raw::*;
+
+/// The `_IOWR('N', 0x41, struct nvme_admin_cmd)` request of `<linux/nvme_ioctl.h>`.
+pub const NVME_IOCTL_ADMIN_CMD: c_ulong = 0xC048_4E41;
+
+/// The opcode of the _Get Log Page_ admin command.
+pub const NVME_ADMIN_GET_LOG_PAGE: u8 = 0x02;
+
+/// The identifier of the _SMART / Health Information_ log page.
+pub const NVME_LOG_SMART: u32 = 0x02;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct nvme_admin_cmd {
+    pub opcode: u8,
+    pub flags: u8,
+    pub rsvd1: u16,
+    pub nsid: u32,
+    pub cdw2: u32,
+    pub cdw3: u32,
+    pub metadata: u64,
+    pub addr: u64,
+    pub metadata_len: u32,
+    pub data_len: u32,
+    pub cdw10: u32,
+    pub cdw11: u32,
+    pub cdw12: u32,
+    pub cdw13: u32,
+    pub cdw14: u32,
+    pub cdw15: u32,
+    pub timeout_ms: u32,
+    pub result: u32,
+}
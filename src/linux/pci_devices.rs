@@ -1,7 +1,7 @@
 use std::{
     fs::{read_dir, read_to_string},
     io,
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
 use pciid_parser::{schema::SubDeviceId, Database};
@@ -42,6 +42,26 @@ impl PciDevice {
         PciDevice { base_path }
     }
 
+    /// Returns the device located at `path`, provided it exposes every value we might read.
+    pub fn from_path(path: &Path) -> Option<PciDevice> {
+        let readable_values = [
+            PciDeviceReadableValues::Class,
+            PciDeviceReadableValues::Vendor,
+            PciDeviceReadableValues::Device,
+            PciDeviceReadableValues::SubVendor,
+            PciDeviceReadableValues::SubDevice,
+        ];
+
+        if readable_values
+            .iter()
+            .all(|value| path.join(value.as_str()).is_file())
+        {
+            return Some(PciDevice::new(path.to_path_buf()));
+        }
+
+        None
+    }
+
     fn read_value(&self, readable_value: PciDeviceReadableValues) -> String {
         let value_path = self.base_path.join(readable_value.as_str());
 
@@ -62,19 +82,30 @@ impl PciDevice {
         }
     }
 
+    /// Checks the device's class directly, without looking it up in the PCI ID database: every
+    /// display controller, which is what [PciDevice::is_gpu] looks for, belongs to class `0x03`.
+    pub fn is_display_controller(&self) -> bool {
+        self.read_value(PciDeviceReadableValues::Class)
+            .starts_with("03")
+    }
+
+    /// Returns the address of the device, e.g. `0000:01:00`, leaving out its function.
+    #[cfg(feature = "nvml")]
+    pub fn address(&self) -> Option<String> {
+        let name = self.base_path.file_name()?.to_str()?;
+        let (address, _function) = name.rsplit_once('.')?;
+
+        Some(address.to_lowercase())
+    }
+
     pub fn get_device_name(&self, db: &Database) -> Option<String> {
         let vendor_value = self.read_value(PciDeviceReadableValues::Vendor);
         let sub_vendor_value = self.read_value(PciDeviceReadableValues::SubVendor);
         let device_value = self.read_value(PciDeviceReadableValues::Device);
         let sub_device_value = self.read_value(PciDeviceReadableValues::SubDevice);
 
-        let Some(vendor) = db.vendors.get(&vendor_value) else {
-            return None;
-        };
-
-        let Some(device) = vendor.devices.get(&device_value) else {
-            return None;
-        };
+        let vendor = db.vendors.get(&vendor_value)?;
+        let device = vendor.devices.get(&device_value)?;
         // To return device name if no valid subdevice name is found
         let device_name = device.name.to_owned();
 
@@ -18,9 +18,32 @@ use std::os::raw::c_uint;
 type host_flavor_t = integer_t;
 type host_info64_t = *mut integer_t;
 pub type io_object_t = mach_port_t;
+pub type csr_config_t = u32;
+
+/// Lifts the protection of system locations against modification, which is the core of
+/// System Integrity Protection.
+pub const CSR_ALLOW_UNRESTRICTED_FS: csr_config_t = 1 << 1;
 pub type io_service_t = io_object_t;
 pub type IOOptionBits = c_uint;
 pub type io_registry_entry_t = io_object_t;
+pub type AudioObjectID = u32;
+pub type OSStatus = i32;
+pub type TISInputSourceRef = *const std::ffi::c_void;
+
+pub const kAudioObjectSystemObject: AudioObjectID = 1;
+pub const kAudioObjectUnknown: AudioObjectID = 0;
+pub const kAudioHardwarePropertyDefaultOutputDevice: u32 = u32::from_be_bytes(*b"dOut");
+pub const kAudioObjectPropertyName: u32 = u32::from_be_bytes(*b"lnam");
+pub const kAudioObjectPropertyScopeGlobal: u32 = u32::from_be_bytes(*b"glob");
+pub const kAudioObjectPropertyElementMain: u32 = 0;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct AudioObjectPropertyAddress {
+    pub selector: u32,
+    pub scope: u32,
+    pub element: u32,
+}
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Default)]
@@ -78,7 +101,36 @@ extern "C" {
         options: IOOptionBits,
     ) -> kern_return_t;
 
+    pub fn IORegistryEntryCreateCFProperty(
+        entry: io_registry_entry_t,
+        key: CFStringRef,
+        allocator: CFAllocatorRef,
+        options: IOOptionBits,
+    ) -> CFTypeRef;
+
     pub fn IOObjectRelease(object: io_object_t) -> kern_return_t;
 
+    pub fn csr_get_active_config(config: *mut csr_config_t) -> i32;
+
+    pub fn TISCopyCurrentKeyboardInputSource() -> TISInputSourceRef;
+
+    pub fn TISGetInputSourceProperty(
+        source: TISInputSourceRef,
+        key: CFStringRef,
+    ) -> *const std::ffi::c_void;
+
+    pub static kTISPropertyInputSourceID: CFStringRef;
+
+    pub fn CFLocaleCopyPreferredLanguages() -> CFArrayRef;
+
     pub fn DisplayServicesGetBrightness(id: CGDirectDisplayID, brightness: *mut f32) -> i32;
+
+    pub fn AudioObjectGetPropertyData(
+        object_id: AudioObjectID,
+        address: *const AudioObjectPropertyAddress,
+        qualifier_data_size: u32,
+        qualifier_data: *const std::ffi::c_void,
+        data_size: *mut u32,
+        data: *mut std::ffi::c_void,
+    ) -> OSStatus;
 }
@@ -1,17 +1,28 @@
 #![allow(clippy::unnecessary_cast)]
 use crate::extra;
-use crate::macos::mach_ffi::{io_registry_entry_t, DisplayServicesGetBrightness, IOObjectRelease};
+#[cfg(feature = "full")]
+use crate::macos::mach_ffi::IORegistryEntryCreateCFProperties;
 use crate::macos::mach_ffi::{
-    kIOMasterPortDefault, vm_statistics64, IORegistryEntryCreateCFProperties,
-    IOServiceGetMatchingService, IOServiceMatching,
+    csr_config_t, csr_get_active_config, io_registry_entry_t, DisplayServicesGetBrightness,
+    IOObjectRelease, CSR_ALLOW_UNRESTRICTED_FS,
+};
+use crate::macos::mach_ffi::{
+    kIOMasterPortDefault, kTISPropertyInputSourceID, vm_statistics64,
+    CFLocaleCopyPreferredLanguages, IORegistryEntryCreateCFProperty, IOServiceGetMatchingService,
+    IOServiceMatching, TISCopyCurrentKeyboardInputSource, TISGetInputSourceProperty,
 };
 use crate::shared;
 use crate::traits::ReadoutError::MetricNotAvailable;
 use crate::traits::*;
-use core_foundation::base::{TCFType, ToVoid};
+use core_foundation::array::CFArray;
+#[cfg(feature = "full")]
+use core_foundation::base::ToVoid;
+use core_foundation::base::{CFRelease, TCFType};
+#[cfg(feature = "full")]
 use core_foundation::dictionary::{CFMutableDictionary, CFMutableDictionaryRef};
+#[cfg(feature = "full")]
 use core_foundation::number::{CFNumber, CFNumberRef};
-use core_foundation::string::CFString;
+use core_foundation::string::{CFString, CFStringRef};
 use core_graphics::display::{CGDisplay, CGMainDisplayID};
 use core_video_sys::{
     kCVTimeIsIndefinite, CVDisplayLinkCreateWithCGDisplay,
@@ -19,11 +30,14 @@ use core_video_sys::{
 };
 use mach2::kern_return::KERN_SUCCESS;
 use std::ffi::CString;
+#[cfg(feature = "full")]
 use std::fs::DirEntry;
 use sysctl::{Ctl, Sysctl};
 
 mod mach_ffi;
+mod models;
 
+#[cfg(feature = "full")]
 pub struct MacOSBatteryReadout {
     power_info: Result<MacOSIOPMPowerSource, ReadoutError>,
 }
@@ -35,6 +49,7 @@ pub struct MacOSProductReadout {
 pub struct MacOSKernelReadout {
     os_type_ctl: Option<Ctl>,
     os_release_ctl: Option<Ctl>,
+    os_product_version_ctl: Option<Ctl>,
 }
 
 pub struct MacOSGeneralReadout {
@@ -49,17 +64,24 @@ pub struct MacOSMemoryReadout {
     physical_memory: i64,
 }
 
+#[cfg(feature = "full")]
 #[derive(Debug, Default)]
 struct MacOSIOPMPowerSource {
     battery_installed: Option<bool>,
     state_of_charge: Option<usize>,
     charging: Option<bool>,
+    manufacturer: Option<String>,
+    device_name: Option<String>,
 }
 
+#[cfg(feature = "full")]
 pub struct MacOSPackageReadout;
 
+#[cfg(feature = "full")]
 pub struct MacOSNetworkReadout;
+pub struct MacOSProcessReadout;
 
+#[cfg(feature = "full")]
 impl BatteryReadout for MacOSBatteryReadout {
     fn new() -> Self {
         MacOSBatteryReadout {
@@ -96,8 +118,36 @@ impl BatteryReadout for MacOSBatteryReadout {
     fn health(&self) -> Result<u8, ReadoutError> {
         Err(ReadoutError::NotImplemented)
     }
+
+    fn manufacturer(&self) -> Result<String, ReadoutError> {
+        let power_info = self.power_info.as_ref()?;
+
+        power_info.manufacturer.clone().ok_or_else(|| {
+            ReadoutError::Other(String::from(
+                "Manufacturer property was not present in the dictionary that was returned from IOKit.",
+            ))
+        })
+    }
+
+    fn model(&self) -> Result<String, ReadoutError> {
+        let power_info = self.power_info.as_ref()?;
+
+        power_info.device_name.clone().ok_or_else(|| {
+            ReadoutError::Other(String::from(
+                "DeviceName property was not present in the dictionary that was returned from IOKit.",
+            ))
+        })
+    }
+}
+
+#[cfg(feature = "full")]
+impl Refreshable for MacOSBatteryReadout {
+    fn refresh(&mut self) {
+        self.power_info = MacOSIOPMPowerSource::new();
+    }
 }
 
+#[cfg(feature = "full")]
 impl MacOSIOPMPowerSource {
     fn new() -> Result<Self, ReadoutError> {
         let battery_data_key = CFString::new("BatteryData");
@@ -134,6 +184,20 @@ impl MacOSIOPMPowerSource {
                 let number = CFNumber::wrap_under_get_rule((*charging) as CFNumberRef);
                 instance.charging = Some(number.to_i32() != Some(0));
             }
+
+            if let Some(manufacturer) =
+                power_source_dict.find(&CFString::new("Manufacturer").to_void())
+            {
+                let string = CFString::wrap_under_get_rule((*manufacturer) as CFStringRef);
+                instance.manufacturer = Some(string.to_string());
+            }
+
+            if let Some(device_name) =
+                power_source_dict.find(&CFString::new("DeviceName").to_void())
+            {
+                let string = CFString::wrap_under_get_rule((*device_name) as CFStringRef);
+                instance.device_name = Some(string.to_string());
+            }
         }
 
         Ok(instance)
@@ -179,15 +243,14 @@ impl KernelReadout for MacOSKernelReadout {
         MacOSKernelReadout {
             os_type_ctl: Ctl::new("kern.ostype").ok(),
             os_release_ctl: Ctl::new("kern.osrelease").ok(),
+            os_product_version_ctl: Ctl::new("kern.osproductversion").ok(),
         }
     }
 
     fn os_release(&self) -> Result<String, ReadoutError> {
-        Ok(self
-            .os_release_ctl
-            .as_ref()
-            .ok_or(MetricNotAvailable)?
-            .value_string()?)
+        // The version of macOS itself, patch version included, e.g. 14.4.1, rather than
+        // the release of Darwin, which pretty_kernel reports instead.
+        product_version(self.os_product_version_ctl.as_ref())
     }
 
     fn os_type(&self) -> Result<String, ReadoutError> {
@@ -199,7 +262,39 @@ impl KernelReadout for MacOSKernelReadout {
     }
 
     fn pretty_kernel(&self) -> Result<String, ReadoutError> {
-        Ok(format!("{} {}", self.os_type()?, self.os_release()?))
+        let darwin_release = self
+            .os_release_ctl
+            .as_ref()
+            .ok_or(MetricNotAvailable)?
+            .value_string()?;
+
+        Ok(format!("{} {darwin_release}", self.os_type()?))
+    }
+
+    fn kernel_parameters(&self) -> Result<String, ReadoutError> {
+        use std::process::{Command, Stdio};
+
+        if !extra::which("nvram") {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        // "nvram boot-args" prints the variable name followed by a tab and its value,
+        // and exits with a non-zero status if no boot arguments have been set.
+        let output = Command::new("nvram")
+            .arg("boot-args")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .output()?;
+
+        if !output.status.success() {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        let boot_args = String::from_utf8_lossy(&output.stdout);
+        match boot_args.split_once('\t') {
+            Some((_, value)) => Ok(value.trim().to_string()),
+            None => Err(ReadoutError::MetricNotAvailable),
+        }
     }
 }
 
@@ -286,6 +381,10 @@ impl GeneralReadout for MacOSGeneralReadout {
         shared::username()
     }
 
+    fn logged_in_user_names(&self) -> Result<Vec<String>, ReadoutError> {
+        shared::logged_in_user_names()
+    }
+
     fn hostname(&self) -> Result<String, ReadoutError> {
         Ok(self
             .hostname_ctl
@@ -341,6 +440,41 @@ impl GeneralReadout for MacOSGeneralReadout {
         Err(MetricNotAvailable)
     }
 
+    fn system_language(&self) -> Result<String, ReadoutError> {
+        // The languages the user prefers, in order, of which the first one is the one the
+        // user interface is displayed in. These are already formatted as language tags.
+        let languages = unsafe {
+            let languages = CFLocaleCopyPreferredLanguages();
+            if languages.is_null() {
+                return Err(MetricNotAvailable);
+            }
+
+            CFArray::<CFString>::wrap_under_create_rule(languages)
+        };
+
+        let language = languages.get(0).ok_or(MetricNotAvailable)?;
+        Ok(language.to_string())
+    }
+
+    fn hibernation_supported(&self) -> Result<bool, ReadoutError> {
+        use std::process::{Command, Stdio};
+
+        // The power management settings only include a hibernation mode on Macs that
+        // support it, e.g. " hibernatemode        3".
+        let output = Command::new("pmset")
+            .arg("-g")
+            .stderr(Stdio::null())
+            .output()?;
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .any(|line| line.trim_start().starts_with("hibernatemode")))
+    }
+
+    fn terminal_size(&self) -> Result<(u16, u16), ReadoutError> {
+        shared::terminal_size()
+    }
+
     fn shell(&self, shorthand: ShellFormat, kind: ShellKind) -> Result<String, ReadoutError> {
         shared::shell(shorthand, kind)
     }
@@ -365,6 +499,43 @@ impl GeneralReadout for MacOSGeneralReadout {
         shared::cpu_cores()
     }
 
+    fn system_integrity_protection(&self) -> Result<bool, ReadoutError> {
+        let mut config: csr_config_t = 0;
+
+        if unsafe { csr_get_active_config(&mut config) } != 0 {
+            return Err(ReadoutError::Other(String::from(
+                "Unable to read the System Integrity Protection configuration.",
+            )));
+        }
+
+        // Protections can be relaxed individually, we consider SIP to be disabled as soon as
+        // system locations are no longer protected, as "csrutil disable" does.
+        Ok(config & CSR_ALLOW_UNRESTRICTED_FS == 0)
+    }
+
+    fn macos_rosetta(&self) -> Result<bool, ReadoutError> {
+        // This sysctl doesn't exist on Intel Macs, where Rosetta 2 isn't available.
+        let ctl = match Ctl::new("sysctl.proc_translated") {
+            Ok(ctl) => ctl,
+            Err(sysctl::SysctlError::NotFound(_)) => return Ok(false),
+            Err(e) => return Err(e.into()),
+        };
+
+        match ctl.value()? {
+            sysctl::CtlValue::Int(translated) => Ok(translated == 1),
+            _ => Err(MetricNotAvailable),
+        }
+    }
+
+    fn cpu_max_frequency(&self) -> Result<u64, ReadoutError> {
+        // This isn't exposed on Apple silicon, and the frequency is given in Hz.
+        match Ctl::new("hw.cpufrequency_max")?.value()? {
+            sysctl::CtlValue::S64(hz) => Ok(hz as u64 / 1_000_000),
+            sysctl::CtlValue::U64(hz) => Ok(hz / 1_000_000),
+            _ => Err(MetricNotAvailable),
+        }
+    }
+
     fn uptime(&self) -> Result<usize, ReadoutError> {
         use libc::timeval;
         use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -407,36 +578,218 @@ impl GeneralReadout for MacOSGeneralReadout {
     fn gpus(&self) -> Result<Vec<String>, ReadoutError> {
         Err(ReadoutError::NotImplemented)
     }
-}
 
-impl MacOSGeneralReadout {
-    fn operating_system_version(&self) -> Result<NSOperatingSystemVersion, ReadoutError> {
-        let os_string = self
-            .os_product_version_ctl
-            .as_ref()
-            .ok_or(ReadoutError::MetricNotAvailable)?
-            .value_string()?;
+    fn connected_monitors_info(&self) -> Result<Vec<MonitorInfo>, ReadoutError> {
+        let displays = CGDisplay::active_displays().map_err(|e| {
+            ReadoutError::Other(format!("Error while querying active displays: {e}"))
+        })?;
+
+        let monitors = displays
+            .into_iter()
+            .map(CGDisplay::new)
+            .map(|display| {
+                let mode = display.display_mode();
+
+                MonitorInfo {
+                    name: match display.is_builtin() {
+                        true => String::from("Built-in Display"),
+                        false => format!("Display {}", display.id),
+                    },
+                    resolution: (display.pixels_wide() as u32, display.pixels_high() as u32),
+                    refresh_rate: mode
+                        .as_ref()
+                        .map(|mode| mode.refresh_rate().round() as u32)
+                        .unwrap_or_default(),
+                    is_primary: display.is_main(),
+                    color_depth: mode
+                        .as_ref()
+                        .map(|mode| mode.bit_depth() as u32)
+                        .unwrap_or_default(),
+                }
+            })
+            .collect();
+
+        Ok(monitors)
+    }
 
-        let mut string_parts = os_string.split('.');
+    fn color_depth(&self) -> Result<u8, ReadoutError> {
+        let mode = CGDisplay::main().display_mode().ok_or(MetricNotAvailable)?;
+
+        // Pixel encodings that aren't known to Core Graphics come out as zero bits.
+        match mode.bit_depth() {
+            0 => Err(MetricNotAvailable),
+            depth => u8::try_from(depth).map_err(|_| MetricNotAvailable),
+        }
+    }
+
+    fn disk_model(&self, path: &std::path::Path) -> Result<String, ReadoutError> {
+        use std::os::unix::ffi::OsStrExt;
+
+        let c_path = CString::new(path.as_os_str().as_bytes())
+            .map_err(|e| ReadoutError::Other(e.to_string()))?;
+        let mut stat: libc::statfs = unsafe { std::mem::zeroed() };
+        if unsafe { libc::statfs(c_path.as_ptr(), &mut stat) } != 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+
+        // e.g. "/dev/disk3s1s1", the device the filesystem holding the path was mounted from.
+        let device = unsafe { std::ffi::CStr::from_ptr(stat.f_mntfromname.as_ptr()) }
+            .to_string_lossy()
+            .into_owned();
+
+        // APFS volumes live in a synthesized container, the disk that backs it being its
+        // physical store.
+        let mut info = MacOSGeneralReadout::diskutil_info(&device)?;
+        if let Some(store) = info.get("APFS Physical Store").cloned() {
+            info = MacOSGeneralReadout::diskutil_info(&store)?;
+        }
+
+        let model = info
+            .get("Device / Media Name")
+            .cloned()
+            .ok_or(MetricNotAvailable)?;
+
+        match info.get("Protocol").map(String::as_str) {
+            Some("PCI-Express" | "NVMe") => Ok(format!("NVMe {model}")),
+            Some("SATA") => Ok(format!("SATA {model}")),
+            _ => Ok(model),
+        }
+    }
+
+    fn keyboard_layout(&self) -> Result<String, ReadoutError> {
+        let id = unsafe {
+            let source = TISCopyCurrentKeyboardInputSource();
+            if source.is_null() {
+                return Err(MetricNotAvailable);
+            }
+
+            // The property belongs to the input source, so it has to be retained before the
+            // latter is released.
+            let id = TISGetInputSourceProperty(source, kTISPropertyInputSourceID);
+            let id = (!id.is_null()).then(|| CFString::wrap_under_get_rule(id as CFStringRef));
+            CFRelease(source);
+
+            id.ok_or(MetricNotAvailable)?.to_string()
+        };
+
+        // e.g. "com.apple.keylayout.US", whereas input methods are named after themselves.
+        match id.strip_prefix("com.apple.keylayout.") {
+            Some(layout) => Ok(layout.to_string()),
+            None => Ok(id),
+        }
+    }
+
+    fn default_audio_output(&self) -> Result<String, ReadoutError> {
+        use mach_ffi::*;
+
+        let mut address = AudioObjectPropertyAddress {
+            selector: kAudioHardwarePropertyDefaultOutputDevice,
+            scope: kAudioObjectPropertyScopeGlobal,
+            element: kAudioObjectPropertyElementMain,
+        };
 
-        let mut operating_system_version = NSOperatingSystemVersion::default();
+        let mut device: AudioObjectID = kAudioObjectUnknown;
+        let mut size = std::mem::size_of::<AudioObjectID>() as u32;
+        let status = unsafe {
+            AudioObjectGetPropertyData(
+                kAudioObjectSystemObject,
+                &address,
+                0,
+                std::ptr::null(),
+                &mut size,
+                &mut device as *mut AudioObjectID as *mut _,
+            )
+        };
 
-        if let Some(major) = string_parts.next() {
-            operating_system_version.major_version = major.parse().unwrap_or_default()
+        if status != 0 || device == kAudioObjectUnknown {
+            return Err(ReadoutError::Warning(String::from(
+                "No audio output device is available.",
+            )));
         }
 
-        if let Some(minor) = string_parts.next() {
-            operating_system_version.minor_version = minor.parse().unwrap_or_default()
+        address.selector = kAudioObjectPropertyName;
+
+        let mut name: CFStringRef = std::ptr::null();
+        let mut size = std::mem::size_of::<CFStringRef>() as u32;
+        let status = unsafe {
+            AudioObjectGetPropertyData(
+                device,
+                &address,
+                0,
+                std::ptr::null(),
+                &mut size,
+                &mut name as *mut CFStringRef as *mut _,
+            )
+        };
+
+        if status != 0 || name.is_null() {
+            return Err(ReadoutError::Other(format!(
+                "Querying the name of the default audio output device failed with status: {status}"
+            )));
         }
 
-        if let Some(patch) = string_parts.next() {
-            operating_system_version.patch_version = patch.parse().unwrap_or_default()
+        // The caller owns the returned string.
+        Ok(unsafe { CFString::wrap_under_create_rule(name) }.to_string())
+    }
+}
+
+impl MacOSGeneralReadout {
+    /// Returns the properties `diskutil info` lists for the given disk, volume or device.
+    fn diskutil_info(
+        target: &str,
+    ) -> Result<std::collections::HashMap<String, String>, ReadoutError> {
+        use std::process::{Command, Stdio};
+
+        let output = Command::new("diskutil")
+            .args(["info", target])
+            .stderr(Stdio::null())
+            .output()?;
+
+        if !output.status.success() {
+            return Err(ReadoutError::Other(format!(
+                "diskutil could not find {target}."
+            )));
         }
 
-        Ok(operating_system_version)
+        // Properties are listed one per line, e.g. "   Protocol:     Apple Fabric".
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+            .filter(|(_, value)| !value.is_empty())
+            .collect())
+    }
+
+    fn operating_system_version(&self) -> Result<NSOperatingSystemVersion, ReadoutError> {
+        let os_string = product_version(self.os_product_version_ctl.as_ref())?;
+        Ok(NSOperatingSystemVersion::from(os_string.as_str()))
     }
 }
 
+/// Returns the version of macOS as `sw_vers` reports it, _e.g._ `14.4.1`.
+fn product_version(os_product_version_ctl: Option<&Ctl>) -> Result<String, ReadoutError> {
+    match os_product_version_ctl.and_then(|ctl| ctl.value_string().ok()) {
+        Some(os_string) => Ok(os_string.trim().to_string()),
+        // kern.osproductversion was introduced in macOS 10.13.4, earlier releases are
+        // read from the property list sw_vers reads as well.
+        None => {
+            let plist = std::fs::read_to_string(SYSTEM_VERSION_PLIST)?;
+            product_version_from_plist(&plist).ok_or(ReadoutError::MetricNotAvailable)
+        }
+    }
+}
+
+const SYSTEM_VERSION_PLIST: &str = "/System/Library/CoreServices/SystemVersion.plist";
+
+/// Returns the `ProductVersion` held by the given `SystemVersion.plist`, _e.g._ `14.4.1`.
+fn product_version_from_plist(plist: &str) -> Option<String> {
+    let (_, rest) = plist.split_once("<key>ProductVersion</key>")?;
+    let (_, rest) = rest.split_once("<string>")?;
+    let (version, _) = rest.split_once("</string>")?;
+
+    Some(version.trim().to_string())
+}
+
 impl MemoryReadout for MacOSMemoryReadout {
     fn new() -> Self {
         let page_size = match Ctl::new("hw.pagesize").unwrap().value().unwrap() {
@@ -488,9 +841,24 @@ impl MemoryReadout for MacOSMemoryReadout {
 
         Ok(used)
     }
+
+    fn swap_total(&self) -> Result<u64, ReadoutError> {
+        let swap_usage = MacOSMemoryReadout::swap_usage()?;
+        Ok(swap_usage.xsu_total / 1024)
+    }
+
+    fn swap_used(&self) -> Result<u64, ReadoutError> {
+        let swap_usage = MacOSMemoryReadout::swap_usage()?;
+        Ok(swap_usage.xsu_used / 1024)
+    }
 }
 
 impl MacOSMemoryReadout {
+    fn swap_usage() -> Result<libc::xsw_usage, ReadoutError> {
+        let swap_usage = Ctl::new("vm.swapusage")?.value_as::<libc::xsw_usage>()?;
+        Ok(*swap_usage)
+    }
+
     fn mach_vm_stats() -> Result<vm_statistics64, ReadoutError> {
         use mach2::kern_return::KERN_SUCCESS;
         use mach2::message::mach_msg_type_number_t;
@@ -533,6 +901,26 @@ struct NSOperatingSystemVersion {
     patch_version: u64,
 }
 
+impl From<&str> for NSOperatingSystemVersion {
+    /// Parses a version such as `14.4.1`, where the patch version, and even the minor one,
+    /// is left out when it's zero, _e.g._ `14.4`.
+    fn from(s: &str) -> NSOperatingSystemVersion {
+        let mut string_parts = s.trim().split('.');
+        let mut part = || {
+            string_parts
+                .next()
+                .and_then(|part| part.parse().ok())
+                .unwrap_or_default()
+        };
+
+        NSOperatingSystemVersion {
+            major_version: part(),
+            minor_version: part(),
+            patch_version: part(),
+        }
+    }
+}
+
 impl From<NSOperatingSystemVersion> for String {
     fn from(s: NSOperatingSystemVersion) -> String {
         format!(
@@ -566,8 +954,48 @@ impl ProductReadout for MacOSProductReadout {
 
         Ok(mac_model)
     }
+
+    fn product_marketing_name(&self) -> Result<String, ReadoutError> {
+        let identifier = self.product()?;
+
+        models::marketing_name(&identifier)
+            .map(String::from)
+            .ok_or(MetricNotAvailable)
+    }
+
+    fn serial_number(&self) -> Result<String, ReadoutError> {
+        let io_service_name =
+            CString::new("IOPlatformExpertDevice").expect("Unable to create c string");
+        let service = unsafe { IOServiceMatching(io_service_name.as_ptr()) };
+        let entry: io_registry_entry_t =
+            unsafe { IOServiceGetMatchingService(kIOMasterPortDefault, service) };
+
+        if entry == 0 {
+            return Err(MetricNotAvailable);
+        }
+
+        let key = CFString::from_static_string("IOPlatformSerialNumber");
+        let serial = unsafe {
+            let property = IORegistryEntryCreateCFProperty(
+                entry,
+                key.as_concrete_TypeRef(),
+                std::ptr::null(),
+                0,
+            );
+            IOObjectRelease(entry);
+
+            if property.is_null() {
+                return Err(MetricNotAvailable);
+            }
+
+            CFString::wrap_under_create_rule(property as CFStringRef)
+        };
+
+        Ok(serial.to_string())
+    }
 }
 
+#[cfg(feature = "full")]
 impl PackageReadout for MacOSPackageReadout {
     fn new() -> Self {
         MacOSPackageReadout
@@ -585,10 +1013,23 @@ impl PackageReadout for MacOSPackageReadout {
             packages.push((PackageManager::Cargo, c))
         }
 
+        if let Some(c) = MacOSPackageReadout::count_pnpm() {
+            packages.push((PackageManager::Pnpm, c));
+        }
+
+        if let Some(c) = MacOSPackageReadout::count_bun() {
+            packages.push((PackageManager::Bun, c));
+        }
+
+        if let Some(c) = MacOSPackageReadout::count_asdf() {
+            packages.push((PackageManager::Asdf, c));
+        }
+
         packages
     }
 }
 
+#[cfg(feature = "full")]
 impl MacOSPackageReadout {
     /// This method returns the total entries of `/usr/local/Cellar` and `/usr/local/Caskroom` directories
     /// which contain all installed packages of the Homebrew package manager.
@@ -638,8 +1079,21 @@ impl MacOSPackageReadout {
     fn count_cargo() -> Option<usize> {
         shared::count_cargo()
     }
+
+    fn count_pnpm() -> Option<usize> {
+        shared::count_pnpm()
+    }
+
+    fn count_bun() -> Option<usize> {
+        shared::count_bun()
+    }
+
+    fn count_asdf() -> Option<usize> {
+        shared::count_asdf()
+    }
 }
 
+#[cfg(feature = "full")]
 impl NetworkReadout for MacOSNetworkReadout {
     fn new() -> Self {
         MacOSNetworkReadout
@@ -665,9 +1119,71 @@ impl NetworkReadout for MacOSNetworkReadout {
         shared::logical_address(interface)
     }
 
+    fn broadcast_address(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
+        shared::broadcast_address(interface)
+    }
+
+    fn subnet_mask(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
+        shared::subnet_mask(interface)
+    }
+
     fn physical_address(&self, _: Option<&str>) -> Result<String, ReadoutError> {
         Err(ReadoutError::NotImplemented)
     }
+
+    fn tx_errors(&self, interface: Option<&str>) -> Result<usize, ReadoutError> {
+        let (_, oerrs) = MacOSNetworkReadout::interface_errors(interface)?;
+        Ok(oerrs)
+    }
+
+    fn rx_errors(&self, interface: Option<&str>) -> Result<usize, ReadoutError> {
+        let (ierrs, _) = MacOSNetworkReadout::interface_errors(interface)?;
+        Ok(ierrs)
+    }
+}
+
+#[cfg(feature = "full")]
+impl MacOSNetworkReadout {
+    /// Returns the input and output error counters of the given interface, as reported by
+    /// `netstat -I <interface>`.
+    fn interface_errors(interface: Option<&str>) -> Result<(usize, usize), ReadoutError> {
+        use std::process::{Command, Stdio};
+
+        let Some(interface) = interface else {
+            return Err(ReadoutError::Other(String::from(
+                "Please specify a network interface to query.",
+            )));
+        };
+
+        let output = Command::new("netstat")
+            .args(["-n", "-I", interface])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .output()?;
+
+        if !output.status.success() {
+            return Err(MetricNotAvailable);
+        }
+
+        // The link-level row carries the interface's counters:
+        // Name Mtu Network Address Ipkts Ierrs Opkts Oerrs Coll
+        // The address column is empty for interfaces without a hardware address,
+        // so the counters are read from the end of the row.
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let row = stdout
+            .lines()
+            .find(|line| line.contains("<Link#"))
+            .ok_or(MetricNotAvailable)?;
+
+        let fields: Vec<&str> = row.split_whitespace().rev().collect();
+        match (fields.get(3), fields.get(1)) {
+            (Some(ierrs), Some(oerrs)) => match (ierrs.parse(), oerrs.parse()) {
+                (Ok(ierrs), Ok(oerrs)) => Ok((ierrs, oerrs)),
+                _ => Err(MetricNotAvailable),
+            },
+            _ => Err(MetricNotAvailable),
+        }
+    }
 }
 
 fn macos_version_to_name(version: &NSOperatingSystemVersion) -> &'static str {
@@ -695,3 +1211,70 @@ fn macos_version_to_name(version: &NSOperatingSystemVersion) -> &'static str {
         _ => "Unknown",
     }
 }
+
+impl ProcessReadout for MacOSProcessReadout {
+    fn new() -> Self {
+        MacOSProcessReadout
+    }
+
+    fn process_memory(&self) -> Result<u64, ReadoutError> {
+        let mut info = std::mem::MaybeUninit::<libc::mach_task_basic_info>::uninit();
+        let mut count = libc::MACH_TASK_BASIC_INFO_COUNT;
+
+        let kern_return = unsafe {
+            mach2::task::task_info(
+                mach2::traps::mach_task_self(),
+                mach2::task_info::MACH_TASK_BASIC_INFO,
+                info.as_mut_ptr() as mach2::task_info::task_info_t,
+                &mut count,
+            )
+        };
+
+        if kern_return != KERN_SUCCESS {
+            return Err(ReadoutError::Other(format!(
+                "Querying the basic information of the current task failed with return code: {kern_return}"
+            )));
+        }
+
+        Ok(unsafe { info.assume_init() }.resident_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operating_system_version() {
+        assert_eq!(
+            String::from(NSOperatingSystemVersion::from("14.4.1")),
+            "14.4.1"
+        );
+        assert_eq!(
+            String::from(NSOperatingSystemVersion::from("14.4")),
+            "14.4.0"
+        );
+        assert_eq!(String::from(NSOperatingSystemVersion::from("15")), "15.0.0");
+    }
+
+    #[test]
+    fn test_product_version_from_plist() {
+        let plist = r#"<?xml version="1.0" encoding="UTF-8"?>
+<plist version="1.0">
+<dict>
+	<key>ProductBuildVersion</key>
+	<string>23E224</string>
+	<key>ProductName</key>
+	<string>macOS</string>
+	<key>ProductVersion</key>
+	<string>14.4.1</string>
+</dict>
+</plist>"#;
+
+        assert_eq!(
+            product_version_from_plist(plist),
+            Some(String::from("14.4.1"))
+        );
+        assert_eq!(product_version_from_plist("<dict></dict>"), None);
+    }
+}
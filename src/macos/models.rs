@@ -0,0 +1,94 @@
+//! This module maps the model identifiers of Macs, _e.g._ `MacBookPro18,3`, to the names they
+//! are marketed under, as listed by Apple's "Identify your Mac" support articles.
+//!
+//! Identifiers shared by models released in different years, such as `MacBookPro15,1` which
+//! was sold in both 2018 and 2019, are left out as they can't be told apart.
+
+/// Returns the marketing name of the Mac with the given model identifier.
+pub(crate) fn marketing_name(identifier: &str) -> Option<&'static str> {
+    let name = match identifier {
+        // MacBook Air
+        "MacBookAir8,1" => "MacBook Air (Retina, 13-inch, 2018)",
+        "MacBookAir8,2" => "MacBook Air (Retina, 13-inch, 2019)",
+        "MacBookAir9,1" => "MacBook Air (Retina, 13-inch, 2020)",
+        "MacBookAir10,1" => "MacBook Air (M1, 2020)",
+        "Mac14,2" => "MacBook Air (M2, 2022)",
+        "Mac14,15" => "MacBook Air (15-inch, M2, 2023)",
+        "Mac15,12" => "MacBook Air (13-inch, M3, 2024)",
+        "Mac15,13" => "MacBook Air (15-inch, M3, 2024)",
+
+        // MacBook Pro
+        "MacBookPro13,1" => "MacBook Pro (13-inch, 2016, Two Thunderbolt 3 ports)",
+        "MacBookPro13,2" => "MacBook Pro (13-inch, 2016, Four Thunderbolt 3 ports)",
+        "MacBookPro13,3" => "MacBook Pro (15-inch, 2016)",
+        "MacBookPro14,1" => "MacBook Pro (13-inch, 2017, Two Thunderbolt 3 ports)",
+        "MacBookPro14,2" => "MacBook Pro (13-inch, 2017, Four Thunderbolt 3 ports)",
+        "MacBookPro14,3" => "MacBook Pro (15-inch, 2017)",
+        "MacBookPro15,4" => "MacBook Pro (13-inch, 2019, Two Thunderbolt 3 ports)",
+        "MacBookPro16,1" | "MacBookPro16,4" => "MacBook Pro (16-inch, 2019)",
+        "MacBookPro16,2" => "MacBook Pro (13-inch, 2020, Four Thunderbolt 3 ports)",
+        "MacBookPro16,3" => "MacBook Pro (13-inch, 2020, Two Thunderbolt 3 ports)",
+        "MacBookPro17,1" => "MacBook Pro (13-inch, M1, 2020)",
+        "MacBookPro18,1" | "MacBookPro18,2" => "MacBook Pro (16-inch, 2021)",
+        "MacBookPro18,3" | "MacBookPro18,4" => "MacBook Pro (14-inch, 2021)",
+        "Mac14,7" => "MacBook Pro (13-inch, M2, 2022)",
+        "Mac14,5" | "Mac14,9" => "MacBook Pro (14-inch, 2023)",
+        "Mac14,6" | "Mac14,10" => "MacBook Pro (16-inch, 2023)",
+        "Mac15,3" => "MacBook Pro (14-inch, M3, Nov 2023)",
+        "Mac15,6" | "Mac15,8" | "Mac15,10" => "MacBook Pro (14-inch, Nov 2023)",
+        "Mac15,7" | "Mac15,9" | "Mac15,11" => "MacBook Pro (16-inch, Nov 2023)",
+
+        // iMac
+        "iMac19,1" => "iMac (Retina 5K, 27-inch, 2019)",
+        "iMac19,2" => "iMac (Retina 4K, 21.5-inch, 2019)",
+        "iMac20,1" | "iMac20,2" => "iMac (Retina 5K, 27-inch, 2020)",
+        "iMac21,1" | "iMac21,2" => "iMac (24-inch, M1, 2021)",
+        "Mac15,4" | "Mac15,5" => "iMac (24-inch, 2023)",
+        "iMacPro1,1" => "iMac Pro (2017)",
+
+        // Mac mini
+        "Macmini8,1" => "Mac mini (2018)",
+        "Macmini9,1" => "Mac mini (M1, 2020)",
+        "Mac14,3" | "Mac14,12" => "Mac mini (2023)",
+
+        // Mac Studio
+        "Mac13,1" | "Mac13,2" => "Mac Studio (2022)",
+        "Mac14,13" | "Mac14,14" => "Mac Studio (2023)",
+
+        // Mac Pro
+        "MacPro6,1" => "Mac Pro (Late 2013)",
+        "MacPro7,1" => "Mac Pro (2019)",
+        "Mac14,8" => "Mac Pro (2023)",
+
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marketing_name() {
+        assert_eq!(
+            marketing_name("MacBookPro18,3"),
+            Some("MacBook Pro (14-inch, 2021)")
+        );
+        assert_eq!(
+            marketing_name("MacBookAir10,1"),
+            Some("MacBook Air (M1, 2020)")
+        );
+        assert_eq!(marketing_name("Macmini9,1"), Some("Mac mini (M1, 2020)"));
+        assert_eq!(marketing_name("Mac13,1"), Some("Mac Studio (2022)"));
+        assert_eq!(marketing_name("iMac21,2"), Some("iMac (24-inch, M1, 2021)"));
+    }
+
+    #[test]
+    fn test_marketing_name_unknown() {
+        assert_eq!(marketing_name("MacBookPro15,1"), None);
+        assert_eq!(marketing_name("VMware7,1"), None);
+        assert_eq!(marketing_name(""), None);
+    }
+}
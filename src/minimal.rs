@@ -0,0 +1,62 @@
+//! This module contains the readouts left out of builds without the `full` feature,
+//! which take the place of the platform's own and report every metric as not implemented.
+
+use crate::traits::*;
+
+pub struct MinimalBatteryReadout;
+pub struct MinimalPackageReadout;
+pub struct MinimalNetworkReadout;
+
+impl BatteryReadout for MinimalBatteryReadout {
+    fn new() -> Self {
+        MinimalBatteryReadout
+    }
+
+    fn percentage(&self) -> Result<u8, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    fn status(&self) -> Result<BatteryState, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    fn health(&self) -> Result<u8, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+}
+
+impl PackageReadout for MinimalPackageReadout {
+    fn new() -> Self {
+        MinimalPackageReadout
+    }
+}
+
+impl NetworkReadout for MinimalNetworkReadout {
+    fn new() -> Self {
+        MinimalNetworkReadout
+    }
+
+    fn tx_bytes(&self, _interface: Option<&str>) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    fn tx_packets(&self, _interface: Option<&str>) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    fn rx_bytes(&self, _interface: Option<&str>) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    fn rx_packets(&self, _interface: Option<&str>) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    fn logical_address(&self, _interface: Option<&str>) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    fn physical_address(&self, _interface: Option<&str>) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+}
@@ -1,25 +1,35 @@
 #![allow(clippy::unnecessary_cast)]
+#[cfg(feature = "full")]
 use crate::dirs;
 use crate::extra;
 use crate::shared;
 use crate::traits::*;
 use itertools::Itertools;
 use nix::unistd;
+#[cfg(feature = "full")]
 use regex::Regex;
 use std::ffi::CString;
 use std::fs;
+#[cfg(feature = "full")]
 use std::fs::read_dir;
 use std::path::PathBuf;
-use std::process::{Command, Stdio};
+use std::process::Command;
+#[cfg(feature = "full")]
+use std::process::Stdio;
 
+#[cfg(feature = "full")]
 pub struct NetBSDBatteryReadout;
 pub struct NetBSDKernelReadout;
 pub struct NetBSDGeneralReadout;
 pub struct NetBSDMemoryReadout;
 pub struct NetBSDProductReadout;
+#[cfg(feature = "full")]
 pub struct NetBSDPackageReadout;
+#[cfg(feature = "full")]
 pub struct NetBSDNetworkReadout;
+pub struct NetBSDProcessReadout;
 
+#[cfg(feature = "full")]
 impl BatteryReadout for NetBSDBatteryReadout {
     fn new() -> Self {
         NetBSDBatteryReadout
@@ -275,6 +285,10 @@ impl GeneralReadout for NetBSDGeneralReadout {
         Ok(terminal)
     }
 
+    fn terminal_size(&self) -> Result<(u16, u16), ReadoutError> {
+        shared::terminal_size()
+    }
+
     fn shell(&self, shorthand: ShellFormat, kind: ShellKind) -> Result<String, ReadoutError> {
         shared::shell(shorthand, kind)
     }
@@ -296,7 +310,16 @@ impl GeneralReadout for NetBSDGeneralReadout {
     }
 
     fn uptime(&self) -> Result<usize, ReadoutError> {
-        shared::uptime()
+        // procfs isn't mounted by default, unlike Linux, so /proc/uptime can't be relied on.
+        let boot_time: libc::timespec = sysctl_value("kern.boottime")?;
+
+        match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+            // The clock may have been set back since boot.
+            Ok(unix_epoch) => (unix_epoch.as_secs() as usize)
+                .checked_sub(boot_time.tv_sec as usize)
+                .ok_or(ReadoutError::MetricNotAvailable),
+            Err(_) => Err(ReadoutError::MetricNotAvailable),
+        }
     }
 
     fn os_name(&self) -> Result<String, ReadoutError> {
@@ -344,11 +367,13 @@ impl MemoryReadout for NetBSDMemoryReadout {
     }
 
     fn total(&self) -> Result<u64, ReadoutError> {
-        Ok(shared::get_meminfo_value("MemTotal"))
+        let physmem: i64 = sysctl_value("hw.physmem64")?;
+        Ok(physmem as u64 / 1024)
     }
 
     fn free(&self) -> Result<u64, ReadoutError> {
-        Ok(shared::get_meminfo_value("MemFree"))
+        let uvmexp = NetBSDMemoryReadout::uvmexp()?;
+        Ok(uvmexp.free * uvmexp.page_size / 1024)
     }
 
     fn buffers(&self) -> Result<u64, ReadoutError> {
@@ -364,10 +389,45 @@ impl MemoryReadout for NetBSDMemoryReadout {
     }
 
     fn used(&self) -> Result<u64, ReadoutError> {
-        let total = self.total().unwrap();
-        let free = self.free().unwrap();
+        let total = self.total()?;
+        let free = self.free()?;
+
+        Ok(total.saturating_sub(free))
+    }
+
+    fn swap_total(&self) -> Result<u64, ReadoutError> {
+        let uvmexp = NetBSDMemoryReadout::uvmexp()?;
+        Ok(uvmexp.swap_pages * uvmexp.page_size / 1024)
+    }
 
-        Ok(total - free)
+    fn swap_used(&self) -> Result<u64, ReadoutError> {
+        let uvmexp = NetBSDMemoryReadout::uvmexp()?;
+        Ok(uvmexp.swap_pages_in_use * uvmexp.page_size / 1024)
+    }
+}
+
+/// The page counters of the virtual memory system that are of interest to us.
+struct Uvmexp {
+    page_size: u64,
+    free: u64,
+    swap_pages: u64,
+    swap_pages_in_use: u64,
+}
+
+impl NetBSDMemoryReadout {
+    /// Returns the page counters held by `vm.uvmexp2`.
+    fn uvmexp() -> Result<Uvmexp, ReadoutError> {
+        // vm.uvmexp2 is a `struct uvmexp_sysctl` made up of 64-bit integers, which the
+        // kernel is free to append fields to; the buffer leaves plenty of room for them.
+        let fields: [i64; 128] = sysctl_value("vm.uvmexp2")?;
+        let field = |index: usize| fields[index].max(0) as u64;
+
+        Ok(Uvmexp {
+            page_size: field(0),
+            free: field(4),
+            swap_pages: field(17),
+            swap_pages_in_use: field(18),
+        })
     }
 }
 
@@ -413,6 +473,7 @@ impl ProductReadout for NetBSDProductReadout {
     }
 }
 
+#[cfg(feature = "full")]
 impl PackageReadout for NetBSDPackageReadout {
     fn new() -> Self {
         NetBSDPackageReadout
@@ -429,10 +490,19 @@ impl PackageReadout for NetBSDPackageReadout {
             packages.push((PackageManager::Cargo, c));
         }
 
+        if let Some(c) = NetBSDPackageReadout::count_pnpm() {
+            packages.push((PackageManager::Pnpm, c));
+        }
+
+        if let Some(c) = NetBSDPackageReadout::count_bun() {
+            packages.push((PackageManager::Bun, c));
+        }
+
         packages
     }
 }
 
+#[cfg(feature = "full")]
 impl NetBSDPackageReadout {
     fn count_pkgin() -> Option<usize> {
         if let Some(pkg_dbdir) = dirs::pkgdb_dir() {
@@ -453,8 +523,17 @@ impl NetBSDPackageReadout {
     fn count_cargo() -> Option<usize> {
         shared::count_cargo()
     }
+
+    fn count_pnpm() -> Option<usize> {
+        shared::count_pnpm()
+    }
+
+    fn count_bun() -> Option<usize> {
+        shared::count_bun()
+    }
 }
 
+#[cfg(feature = "full")]
 impl NetworkReadout for NetBSDNetworkReadout {
     fn new() -> Self {
         NetBSDNetworkReadout
@@ -480,7 +559,47 @@ impl NetworkReadout for NetBSDNetworkReadout {
         shared::logical_address(interface)
     }
 
+    fn broadcast_address(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
+        shared::broadcast_address(interface)
+    }
+
+    fn subnet_mask(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
+        shared::subnet_mask(interface)
+    }
+
     fn physical_address(&self, _: Option<&str>) -> Result<String, ReadoutError> {
         Err(ReadoutError::NotImplemented)
     }
 }
+
+impl ProcessReadout for NetBSDProcessReadout {
+    fn new() -> Self {
+        NetBSDProcessReadout
+    }
+}
+
+/// Reads the value of the sysctl with the given name, whose type must match the one the
+/// kernel hands out.
+fn sysctl_value<T: Copy>(name: &str) -> Result<T, ReadoutError> {
+    let c_name = CString::new(name).map_err(|e| ReadoutError::Other(e.to_string()))?;
+    // SAFETY: sysctl values are plain integers and structures thereof, for which all zeros
+    // is a valid bit pattern.
+    let mut value: T = unsafe { std::mem::zeroed() };
+    let mut len = std::mem::size_of::<T>();
+
+    let ret = unsafe {
+        libc::sysctlbyname(
+            c_name.as_ptr(),
+            &mut value as *mut T as *mut libc::c_void,
+            &mut len,
+            std::ptr::null(),
+            0,
+        )
+    };
+
+    if ret != 0 {
+        return Err(ReadoutError::Other(format!("Could not get sysctl: {name}")));
+    }
+
+    Ok(value)
+}
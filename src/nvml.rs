@@ -0,0 +1,181 @@
+//! This module loads NVIDIA's Management Library at runtime, which the `nvml` feature
+//! enables, to read the metrics of NVIDIA GPUs that the operating system doesn't expose.
+//!
+//! Hosts without NVIDIA's driver don't have the library, in which case [`Nvml::load`] returns
+//! `None` and readouts fall back to their generic implementation.
+
+use libloading::{Library, Symbol};
+use std::ffi::{c_char, c_int, c_uint, c_void, CStr};
+
+#[cfg(target_os = "linux")]
+const LIBRARY: &str = "libnvidia-ml.so.1";
+#[cfg(target_os = "windows")]
+const LIBRARY: &str = "nvml.dll";
+
+const NVML_SUCCESS: c_int = 0;
+const NVML_TEMPERATURE_GPU: c_int = 0;
+const NVML_DEVICE_NAME_BUFFER_SIZE: usize = 96;
+
+type NvmlDevice = *mut c_void;
+
+#[repr(C)]
+#[derive(Default)]
+struct NvmlPciInfo {
+    bus_id_legacy: [c_char; 16],
+    domain: c_uint,
+    bus: c_uint,
+    device: c_uint,
+    pci_device_id: c_uint,
+    pci_sub_system_id: c_uint,
+    bus_id: [c_char; 32],
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct NvmlMemory {
+    total: u64,
+    free: u64,
+    used: u64,
+}
+
+/// Holds the information NVML reports about a GPU.
+#[derive(Debug, Clone)]
+pub(crate) struct NvmlGpu {
+    /// The marketing name of the GPU, _e.g._ `NVIDIA GeForce RTX 3080`.
+    pub name: String,
+    /// The PCI address of the GPU, _e.g._ `0000:01:00`, leaving out its function.
+    #[cfg_attr(target_os = "windows", allow(dead_code))]
+    pub pci_address: Option<String>,
+    /// The temperature of the GPU, in degrees Celsius.
+    pub temperature: Option<f32>,
+    /// The amount of memory of the GPU, in megabytes.
+    #[cfg_attr(target_os = "windows", allow(dead_code))]
+    pub vram: Option<u64>,
+}
+
+pub(crate) struct Nvml {
+    library: Library,
+}
+
+impl Nvml {
+    /// Loads and initializes NVML, if NVIDIA's driver is installed.
+    pub(crate) fn load() -> Option<Nvml> {
+        let library = unsafe { Library::new(LIBRARY) }.ok()?;
+
+        let init: Symbol<unsafe extern "C" fn() -> c_int> =
+            unsafe { library.get(b"nvmlInit_v2\0") }.ok()?;
+
+        if unsafe { init() } != NVML_SUCCESS {
+            return None;
+        }
+
+        Some(Nvml { library })
+    }
+
+    /// Returns the GPUs managed by NVIDIA's driver, in the order NVML enumerates them.
+    pub(crate) fn gpus(&self) -> Vec<NvmlGpu> {
+        unsafe { self.query_gpus() }.unwrap_or_default()
+    }
+
+    unsafe fn query_gpus(&self) -> Option<Vec<NvmlGpu>> {
+        let count: Symbol<unsafe extern "C" fn(*mut c_uint) -> c_int> =
+            self.library.get(b"nvmlDeviceGetCount_v2\0").ok()?;
+        let handle: Symbol<unsafe extern "C" fn(c_uint, *mut NvmlDevice) -> c_int> =
+            self.library.get(b"nvmlDeviceGetHandleByIndex_v2\0").ok()?;
+        let name: Symbol<unsafe extern "C" fn(NvmlDevice, *mut c_char, c_uint) -> c_int> =
+            self.library.get(b"nvmlDeviceGetName\0").ok()?;
+        let pci_info: Symbol<unsafe extern "C" fn(NvmlDevice, *mut NvmlPciInfo) -> c_int> =
+            self.library.get(b"nvmlDeviceGetPciInfo_v3\0").ok()?;
+        let temperature: Symbol<unsafe extern "C" fn(NvmlDevice, c_int, *mut c_uint) -> c_int> =
+            self.library.get(b"nvmlDeviceGetTemperature\0").ok()?;
+        let memory_info: Symbol<unsafe extern "C" fn(NvmlDevice, *mut NvmlMemory) -> c_int> =
+            self.library.get(b"nvmlDeviceGetMemoryInfo\0").ok()?;
+
+        let mut devices: c_uint = 0;
+        if count(&mut devices) != NVML_SUCCESS {
+            return None;
+        }
+
+        let mut gpus = vec![];
+        for index in 0..devices {
+            let mut device: NvmlDevice = std::ptr::null_mut();
+            if handle(index, &mut device) != NVML_SUCCESS {
+                continue;
+            }
+
+            let mut buffer = [0 as c_char; NVML_DEVICE_NAME_BUFFER_SIZE];
+            if name(device, buffer.as_mut_ptr(), buffer.len() as c_uint) != NVML_SUCCESS {
+                continue;
+            }
+
+            let mut info = NvmlPciInfo::default();
+            let pci_address = (pci_info(device, &mut info) == NVML_SUCCESS)
+                .then(|| format!("{:04x}:{:02x}:{:02x}", info.domain, info.bus, info.device));
+
+            let mut celsius: c_uint = 0;
+            let temperature = (temperature(device, NVML_TEMPERATURE_GPU, &mut celsius)
+                == NVML_SUCCESS)
+                .then_some(celsius as f32);
+
+            // The amount of memory is given in bytes.
+            let mut memory = NvmlMemory::default();
+            let vram = (memory_info(device, &mut memory) == NVML_SUCCESS)
+                .then_some(memory.total / 1024 / 1024);
+
+            gpus.push(NvmlGpu {
+                name: CStr::from_ptr(buffer.as_ptr())
+                    .to_string_lossy()
+                    .into_owned(),
+                pci_address,
+                temperature,
+                vram,
+            });
+        }
+
+        Some(gpus)
+    }
+
+    /// Adds the temperature of the GPUs NVML knows about to `temperatures`, leaving out those
+    /// that are already listed under the same name.
+    pub(crate) fn merge_temperatures(temperatures: &mut Vec<(String, f32)>) {
+        let Some(nvml) = Nvml::load() else {
+            return;
+        };
+
+        for gpu in nvml.gpus() {
+            if let Some(temperature) = gpu.temperature {
+                if !temperatures.iter().any(|(name, _)| *name == gpu.name) {
+                    temperatures.push((gpu.name, temperature));
+                }
+            }
+        }
+    }
+
+    /// Adds the amount of memory of the GPUs NVML knows about to `vram`, leaving out those
+    /// that are already listed under the same name.
+    #[cfg(target_os = "linux")]
+    pub(crate) fn merge_vram(vram: &mut Vec<(String, u64)>) {
+        let Some(nvml) = Nvml::load() else {
+            return;
+        };
+
+        for gpu in nvml.gpus() {
+            if let Some(megabytes) = gpu.vram {
+                if !vram.iter().any(|(name, _)| *name == gpu.name) {
+                    vram.push((gpu.name, megabytes));
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Nvml {
+    fn drop(&mut self) {
+        let shutdown: Result<Symbol<unsafe extern "C" fn() -> c_int>, _> =
+            unsafe { self.library.get(b"nvmlShutdown\0") };
+
+        if let Ok(shutdown) = shutdown {
+            unsafe { shutdown() };
+        }
+    }
+}
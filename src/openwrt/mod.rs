@@ -8,6 +8,7 @@ use std::io::{BufRead, BufReader};
 use sysctl::{Ctl, Sysctl};
 use sysinfo_ffi::sysinfo;
 
+#[cfg(feature = "full")]
 pub struct OpenWrtBatteryReadout;
 
 pub struct OpenWrtKernelReadout {
@@ -25,9 +26,13 @@ pub struct OpenWrtMemoryReadout {
 }
 
 pub struct OpenWrtProductReadout;
+#[cfg(feature = "full")]
 pub struct OpenWrtPackageReadout;
+#[cfg(feature = "full")]
 pub struct OpenWrtNetworkReadout;
+pub struct OpenWrtProcessReadout;
 
+#[cfg(feature = "full")]
 impl BatteryReadout for OpenWrtBatteryReadout {
     fn new() -> Self {
         OpenWrtBatteryReadout
@@ -69,6 +74,10 @@ impl KernelReadout for OpenWrtKernelReadout {
             .ok_or(ReadoutError::MetricNotAvailable)?
             .value_string()?)
     }
+
+    fn kernel_parameters(&self) -> Result<String, ReadoutError> {
+        shared::kernel_parameters()
+    }
 }
 
 impl GeneralReadout for OpenWrtGeneralReadout {
@@ -146,6 +155,10 @@ impl GeneralReadout for OpenWrtGeneralReadout {
         Err(ReadoutError::NotImplemented)
     }
 
+    fn terminal_size(&self) -> Result<(u16, u16), ReadoutError> {
+        shared::terminal_size()
+    }
+
     fn shell(&self, format: ShellFormat, kind: ShellKind) -> Result<String, ReadoutError> {
         shared::shell(format, kind)
     }
@@ -287,8 +300,35 @@ impl MemoryReadout for OpenWrtMemoryReadout {
 
         Ok(total - free - cached - buffers)
     }
+
+    fn swap_total(&self) -> Result<u64, ReadoutError> {
+        let mut info = self.sysinfo;
+        let info_ptr: *mut sysinfo = &mut info;
+        let ret = unsafe { sysinfo(info_ptr) };
+        if ret != -1 {
+            Ok(info.totalswap as u64 * info.mem_unit as u64 / 1024)
+        } else {
+            Err(ReadoutError::Other(String::from(
+                "sysinfo struct returned an error.",
+            )))
+        }
+    }
+
+    fn swap_used(&self) -> Result<u64, ReadoutError> {
+        let mut info = self.sysinfo;
+        let info_ptr: *mut sysinfo = &mut info;
+        let ret = unsafe { sysinfo(info_ptr) };
+        if ret != -1 {
+            Ok((info.totalswap as u64 - info.freeswap as u64) * info.mem_unit as u64 / 1024)
+        } else {
+            Err(ReadoutError::Other(String::from(
+                "sysinfo struct returned an error.",
+            )))
+        }
+    }
 }
 
+#[cfg(feature = "full")]
 impl PackageReadout for OpenWrtPackageReadout {
     fn new() -> Self {
         OpenWrtPackageReadout
@@ -305,6 +345,7 @@ impl PackageReadout for OpenWrtPackageReadout {
     }
 }
 
+#[cfg(feature = "full")]
 impl OpenWrtPackageReadout {
     /// Returns the number of installed packages for systems
     /// that utilize `opkg` as their package manager. \
@@ -328,6 +369,7 @@ impl OpenWrtPackageReadout {
     }
 }
 
+#[cfg(feature = "full")]
 impl NetworkReadout for OpenWrtNetworkReadout {
     fn new() -> Self {
         OpenWrtNetworkReadout
@@ -353,7 +395,21 @@ impl NetworkReadout for OpenWrtNetworkReadout {
         shared::logical_address(interface)
     }
 
+    fn broadcast_address(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
+        shared::broadcast_address(interface)
+    }
+
+    fn subnet_mask(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
+        shared::subnet_mask(interface)
+    }
+
     fn physical_address(&self, _: Option<&str>) -> Result<String, ReadoutError> {
         Err(ReadoutError::NotImplemented)
     }
 }
+
+impl ProcessReadout for OpenWrtProcessReadout {
+    fn new() -> Self {
+        OpenWrtProcessReadout
+    }
+}
@@ -2,13 +2,14 @@
 #![allow(unused_imports)]
 #![allow(clippy::unnecessary_cast)]
 
-use crate::traits::{ReadoutError, ShellFormat, ShellKind};
+use crate::traits::{ProcessInfo, ReadoutError, ShellFormat, ShellKind, SortKey};
 
 use std::fs::read_dir;
 use std::fs::read_to_string;
 use std::io::Error;
 use std::path::Path;
 use std::process::{Command, Stdio};
+use std::time::Duration;
 use std::{env, fs};
 use std::{ffi::CStr, path::PathBuf};
 
@@ -25,11 +26,17 @@ impl From<SysctlError> for ReadoutError {
 
 impl From<std::io::Error> for ReadoutError {
     fn from(e: Error) -> Self {
-        ReadoutError::Other(e.to_string())
+        match e.kind() {
+            std::io::ErrorKind::PermissionDenied => ReadoutError::PermissionDenied(e.to_string()),
+            std::io::ErrorKind::Interrupted
+            | std::io::ErrorKind::TimedOut
+            | std::io::ErrorKind::WouldBlock => ReadoutError::Transient(e.to_string()),
+            _ => ReadoutError::Other(e.to_string()),
+        }
     }
 }
 
-#[cfg(not(any(target_os = "freebsd", target_os = "macos", target_os = "windows")))]
+#[cfg(any(target_os = "linux", target_os = "android"))]
 pub(crate) fn uptime() -> Result<usize, ReadoutError> {
     let uptime_buf = fs::read_to_string("/proc/uptime")?;
     let uptime_str = uptime_buf.split_whitespace().next().unwrap();
@@ -43,6 +50,12 @@ pub(crate) fn uptime() -> Result<usize, ReadoutError> {
     }
 }
 
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn kernel_parameters() -> Result<String, ReadoutError> {
+    let cmdline = fs::read_to_string("/proc/cmdline")?;
+    Ok(crate::extra::pop_newline(cmdline))
+}
+
 #[cfg(not(any(
     feature = "openwrt",
     target_os = "android",
@@ -144,6 +157,47 @@ pub(crate) fn username() -> Result<String, ReadoutError> {
     )))
 }
 
+/// Returns the names of the users that have an active login session, as recorded in the
+/// `utmpx` database.
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "freebsd"))]
+pub(crate) fn logged_in_user_names() -> Result<Vec<String>, ReadoutError> {
+    let mut users: Vec<String> = vec![];
+
+    unsafe {
+        libc::setutxent();
+
+        loop {
+            let entry = libc::getutxent();
+            if entry.is_null() {
+                break;
+            }
+
+            if (*entry).ut_type != libc::USER_PROCESS {
+                continue;
+            }
+
+            // The name isn't nul-terminated when it fills the whole field.
+            let name: Vec<u8> = (*entry)
+                .ut_user
+                .iter()
+                .take_while(|&&c| c != 0)
+                .map(|&c| c as u8)
+                .collect();
+
+            if !name.is_empty() {
+                users.push(String::from_utf8_lossy(&name).into_owned());
+            }
+        }
+
+        libc::endutxent();
+    }
+
+    users.sort();
+    users.dedup();
+
+    Ok(users)
+}
+
 #[cfg(target_family = "unix")]
 pub(crate) fn shell(shorthand: ShellFormat, kind: ShellKind) -> Result<String, ReadoutError> {
     match kind {
@@ -274,7 +328,7 @@ pub(crate) fn disk_space(path: String) -> Result<(u64, u64), ReadoutError> {
 }
 
 /// Obtain the value of a specified field from `/proc/meminfo` needed to calculate memory usage
-#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+#[cfg(any(target_os = "linux", target_os = "android"))]
 pub(crate) fn get_meminfo_value(value: &str) -> u64 {
     use std::io::{BufRead, BufReader};
     let file = fs::File::open("/proc/meminfo");
@@ -293,7 +347,7 @@ pub(crate) fn get_meminfo_value(value: &str) -> u64 {
     }
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(all(not(target_os = "windows"), feature = "full"))]
 pub(crate) fn logical_address(interface: Option<&str>) -> Result<String, ReadoutError> {
     if let Some(ifname) = interface {
         if let Some(addr) = if_addrs::get_if_addrs()?.into_iter().find_map(|i| {
@@ -319,12 +373,458 @@ pub(crate) fn logical_address(interface: Option<&str>) -> Result<String, Readout
     )))
 }
 
+/// Returns the first IPv4 address assigned to the given interface.
+#[cfg(all(not(target_os = "windows"), feature = "full"))]
+pub(crate) fn ipv4_interface(interface: Option<&str>) -> Result<if_addrs::Ifv4Addr, ReadoutError> {
+    let Some(ifname) = interface else {
+        return Err(ReadoutError::Other(String::from(
+            "Please specify a network interface to query.",
+        )));
+    };
+
+    if_addrs::get_if_addrs()?
+        .into_iter()
+        .filter(|i| i.name == ifname)
+        .find_map(|i| match i.addr {
+            if_addrs::IfAddr::V4(v4_addr) => Some(v4_addr),
+            _ => None,
+        })
+        .ok_or(ReadoutError::MetricNotAvailable)
+}
+
+#[cfg(all(not(target_os = "windows"), feature = "full"))]
+pub(crate) fn broadcast_address(interface: Option<&str>) -> Result<String, ReadoutError> {
+    ipv4_interface(interface)?
+        .broadcast
+        .map(|broadcast| broadcast.to_string())
+        .ok_or(ReadoutError::MetricNotAvailable)
+}
+
+#[cfg(all(not(target_os = "windows"), feature = "full"))]
+pub(crate) fn subnet_mask(interface: Option<&str>) -> Result<String, ReadoutError> {
+    Ok(ipv4_interface(interface)?.netmask.to_string())
+}
+
+/// Returns the panel technology held by the _Display Device Data_ block of the _DisplayID_
+/// extensions of the given _EDID_, if it's one of OLED, IPS, VA or TN.
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+pub(crate) fn edid_display_technology(edid: &[u8]) -> Option<&'static str> {
+    const DISPLAYID_EXTENSION: u8 = 0x70;
+    const DISPLAY_DEVICE_DATA: u8 = 0x0C;
+
+    // Extensions follow the 128-byte base block, each 128 bytes long as well. A DisplayID
+    // section starts at their second byte, its data blocks coming after a 4-byte header.
+    for extension in edid.chunks_exact(128).skip(1) {
+        if extension[0] != DISPLAYID_EXTENSION {
+            continue;
+        }
+
+        let end = (5 + extension[2] as usize).min(extension.len() - 1);
+        let mut offset = 5;
+
+        // Each data block is made up of its tag, revision and payload length, followed by
+        // the payload itself.
+        while offset + 3 <= end {
+            let (tag, len) = (extension[offset], extension[offset + 2] as usize);
+            if tag == DISPLAY_DEVICE_DATA && len > 0 && offset + 3 < end {
+                return match extension[offset + 3] {
+                    0x10 | 0x14 => Some("TN"),
+                    0x15 => Some("IPS"),
+                    0x16 => Some("VA"),
+                    0x50 => Some("OLED"),
+                    _ => None,
+                };
+            }
+
+            offset += 3 + len;
+        }
+    }
+
+    None
+}
+
+/// Converts a POSIX locale name, _e.g._ `en_US.UTF-8`, to a _BCP 47_ language tag, _e.g._
+/// `en-US`. The `C` and `POSIX` locales don't name a language, so `None` is returned for them.
+#[cfg(target_os = "linux")]
+pub(crate) fn locale_to_language_tag(locale: &str) -> Option<String> {
+    // Neither the codeset nor the modifier, e.g. "@euro", are part of the language.
+    let locale = locale.split(['.', '@']).next()?.trim();
+
+    if locale.is_empty() || locale == "C" || locale == "POSIX" {
+        return None;
+    }
+
+    match locale.split_once('_') {
+        Some((language, territory)) => Some(format!(
+            "{}-{}",
+            language.to_lowercase(),
+            territory.to_uppercase()
+        )),
+        None => Some(locale.to_lowercase()),
+    }
+}
+
+/// Returns the number of columns and rows of the terminal the standard output is attached to.
+#[cfg(target_family = "unix")]
+pub(crate) fn terminal_size() -> Result<(u16, u16), ReadoutError> {
+    if unsafe { libc::isatty(libc::STDOUT_FILENO) } == 0 {
+        return Err(ReadoutError::Warning(String::from(
+            "The standard output is not a terminal.",
+        )));
+    }
+
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    if unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } != 0 {
+        return Err(Error::last_os_error().into());
+    }
+
+    // Some terminals, such as serial consoles, don't know their own size.
+    if size.ws_col == 0 || size.ws_row == 0 {
+        return Err(ReadoutError::MetricNotAvailable);
+    }
+
+    Ok((size.ws_col, size.ws_row))
+}
+
+/// Returns the number of crates installed through `cargo install`.
+///
+/// These are read from `.crates2.json`, in which cargo keeps track of them, as counting the
+/// binaries of `$CARGO_HOME/bin` misses crates that don't provide any and counts those that
+/// provide several more than once. The latter is only done if the former doesn't exist.
 pub(crate) fn count_cargo() -> Option<usize> {
-    let bin = home::cargo_home().ok()?.join("bin");
-    let read_dir = read_dir(bin).ok()?;
+    let cargo_home = home::cargo_home().ok()?;
+
+    let count = match parse_crates2_json(&cargo_home.join(".crates2.json")) {
+        Some(count) => count,
+        None => read_dir(cargo_home.join("bin")).ok()?.count(),
+    };
 
-    match read_dir.count() {
+    match count {
         0 => None,
         pkgs => Some(pkgs),
     }
 }
+
+/// Returns the number of crates listed by the given `.crates2.json`, _i.e._ the number of
+/// keys of its `installs` object, which are formatted as `<name> <version> (<source>)`.
+pub(crate) fn parse_crates2_json(path: &Path) -> Option<usize> {
+    count_crates2_installs(&read_to_string(path).ok()?)
+}
+
+fn count_crates2_installs(json: &str) -> Option<usize> {
+    let installs = json.find("\"installs\"")? + "\"installs\"".len();
+    let mut chars = json[installs..]
+        .chars()
+        .skip_while(|c| c.is_whitespace() || *c == ':');
+
+    if chars.next()? != '{' {
+        return None;
+    }
+
+    let (mut depth, mut count) = (1, 0);
+    let (mut in_string, mut escaped, mut expecting_key) = (false, false, true);
+
+    for c in chars {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+
+            continue;
+        }
+
+        match c {
+            '"' => {
+                if depth == 1 && expecting_key {
+                    count += 1;
+                    expecting_key = false;
+                }
+
+                in_string = true;
+            }
+            '{' | '[' => depth += 1,
+            '}' | ']' => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(count);
+                }
+            }
+            ',' if depth == 1 => expecting_key = true,
+            _ => {}
+        }
+    }
+
+    // The object was never closed.
+    None
+}
+
+/// Returns the number of packages installed globally through `pnpm`.
+pub(crate) fn count_pnpm() -> Option<usize> {
+    let home = home::home_dir()?;
+    let pnpm_home = env::var_os("PNPM_HOME")
+        .map(PathBuf::from)
+        .or_else(|| default_pnpm_home(&home))?;
+
+    // Since pnpm 7, global packages live within PNPM_HOME, whereas earlier versions kept them
+    // in a directory of their own. Both are laid out as "<layout version>/node_modules".
+    let node_modules = [pnpm_home.join("global"), home.join(".pnpm-global")]
+        .into_iter()
+        .find_map(|global| {
+            read_dir(global)
+                .ok()?
+                .flatten()
+                .filter_map(|layout| {
+                    let version = layout.file_name().to_str()?.parse::<u32>().ok()?;
+                    Some((version, layout.path().join("node_modules")))
+                })
+                .filter(|(_, node_modules)| node_modules.is_dir())
+                .max_by_key(|(version, _)| *version)
+                .map(|(_, node_modules)| node_modules)
+        })?;
+
+    match count_node_modules(&node_modules) {
+        0 => None,
+        pkgs => Some(pkgs),
+    }
+}
+
+/// Returns the number of packages installed globally through `bun`.
+pub(crate) fn count_bun() -> Option<usize> {
+    let bun_install = env::var_os("BUN_INSTALL")
+        .map(PathBuf::from)
+        .or_else(|| Some(home::home_dir()?.join(".bun")))?;
+
+    match count_node_modules(&bun_install.join("install/global/node_modules")) {
+        0 => None,
+        pkgs => Some(pkgs),
+    }
+}
+
+/// Returns the number of tools installed through `asdf`, counting each tool once no matter
+/// how many of its versions are installed.
+pub(crate) fn count_asdf() -> Option<usize> {
+    // ASDF_DATA_DIR is where asdf keeps its installs, which defaults to ASDF_DIR, where asdf
+    // itself is installed.
+    let asdf = env::var_os("ASDF_DATA_DIR")
+        .or_else(|| env::var_os("ASDF_DIR"))
+        .map(PathBuf::from)
+        .or_else(|| Some(home::home_dir()?.join(".asdf")))?;
+
+    let tools = read_dir(asdf.join("installs"))
+        .ok()?
+        .flatten()
+        .filter(|tool| read_dir(tool.path()).is_ok_and(|mut versions| versions.next().is_some()))
+        .count();
+
+    match tools {
+        0 => None,
+        tools => Some(tools),
+    }
+}
+
+/// Returns the location pnpm stores its global packages in when `PNPM_HOME` isn't set.
+fn default_pnpm_home(home: &Path) -> Option<PathBuf> {
+    if cfg!(target_os = "windows") {
+        env::var_os("LOCALAPPDATA").map(|local| PathBuf::from(local).join("pnpm"))
+    } else if cfg!(target_os = "macos") {
+        Some(home.join("Library/pnpm"))
+    } else {
+        let data_home = env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| home.join(".local/share"));
+        Some(data_home.join("pnpm"))
+    }
+}
+
+/// Returns the number of packages found in a `node_modules` directory, where scoped packages,
+/// _e.g._ `@scope/pkg`, are nested in a directory named after their scope.
+fn count_node_modules(node_modules: &Path) -> usize {
+    let Ok(entries) = read_dir(node_modules) else {
+        return 0;
+    };
+
+    entries
+        .flatten()
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        // Hidden entries, e.g. ".bin" or ".pnpm", hold the package manager's own files.
+        .filter(|name| !name.starts_with('.'))
+        .map(|name| {
+            if name.starts_with('@') {
+                read_dir(node_modules.join(name)).map_or(0, |scope| scope.count())
+            } else {
+                1
+            }
+        })
+        .sum()
+}
+
+/// How long processes are observed for when measuring their CPU usage.
+pub(crate) const PROCESS_SAMPLING_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Ranks `processes` by `by`, heaviest first, and keeps the first `n` of them.
+pub(crate) fn top_processes(
+    mut processes: Vec<ProcessInfo>,
+    n: usize,
+    by: SortKey,
+) -> Vec<ProcessInfo> {
+    match by {
+        SortKey::Cpu => processes.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage)),
+        SortKey::Memory => processes.sort_by_key(|p| std::cmp::Reverse(p.rss)),
+    }
+
+    processes.truncate(n);
+    processes
+}
+
+/// Holds the options an uptime is formatted with by [format_uptime].
+///
+/// New options may be added, so it's built from [UptimeFormat::default] before setting the
+/// fields that differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct UptimeFormat {
+    /// Whether units are abbreviated, _e.g._ `3d 4h` rather than `3 days, 4 hours`.
+    pub abbreviated: bool,
+    /// Whether minutes are shown.
+    pub minutes: bool,
+    /// Whether seconds are shown, which implies minutes are shown too.
+    pub seconds: bool,
+}
+
+impl Default for UptimeFormat {
+    fn default() -> Self {
+        UptimeFormat {
+            abbreviated: false,
+            minutes: true,
+            seconds: false,
+        }
+    }
+}
+
+/// Formats an uptime of `secs` seconds the way neofetch does, _e.g._ `3 days, 4 hours, 1
+/// minute`, leaving out the units that amount to zero.
+///
+/// An uptime shorter than the smallest unit shown is given as zero of that unit, _e.g._
+/// `0 minutes`.
+pub fn format_uptime(secs: u64, opts: UptimeFormat) -> String {
+    let mut units = vec![(secs / 86400, "day", "d"), (secs / 3600 % 24, "hour", "h")];
+    if opts.minutes || opts.seconds {
+        units.push((secs / 60 % 60, "minute", "m"));
+    }
+    if opts.seconds {
+        units.push((secs % 60, "second", "s"));
+    }
+
+    let format = |&(count, unit, short): &(u64, &str, &str)| match (opts.abbreviated, count) {
+        (true, _) => format!("{count}{short}"),
+        (false, 1) => format!("1 {unit}"),
+        (false, _) => format!("{count} {unit}s"),
+    };
+
+    let mut parts: Vec<String> = units
+        .iter()
+        .filter(|(count, _, _)| *count != 0)
+        .map(format)
+        .collect();
+
+    if parts.is_empty() {
+        let &(_, unit, short) = units.last().unwrap();
+        parts.push(format(&(0, unit, short)));
+    }
+
+    parts.join(if opts.abbreviated { " " } else { ", " })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_crates2_installs() {
+        let json = r#"{"installs":{"ripgrep 14.1.0 (registry+https://github.com/rust-lang/crates.io-index)":{"version_req":null,"bins":["rg"],"features":[],"all_features":false,"no_default_features":false,"profile":"release","target":"x86_64-unknown-linux-gnu","rustc":"rustc 1.76.0 (07dca489a 2024-02-04)\nbinary: rustc"},"cargo-edit 0.12.2 (registry+https://github.com/rust-lang/crates.io-index)":{"version_req":null,"bins":["cargo-add","cargo-rm","cargo-set-version","cargo-upgrade"],"features":[],"all_features":false,"no_default_features":false,"profile":"release","target":"x86_64-unknown-linux-gnu","rustc":"rustc 1.76.0 (07dca489a 2024-02-04)\nbinary: rustc"}}}"#;
+
+        assert_eq!(count_crates2_installs(json), Some(2));
+        assert_eq!(count_crates2_installs(r#"{"installs": {}}"#), Some(0));
+        assert_eq!(
+            count_crates2_installs(r#"{"installs":{"a 1.0.0 (path+file:///a)":{"#),
+            None
+        );
+        assert_eq!(count_crates2_installs("{}"), None);
+    }
+
+    #[test]
+    fn test_format_uptime() {
+        let opts = UptimeFormat::default();
+        assert_eq!(format_uptime(273_900, opts), "3 days, 4 hours, 5 minutes");
+        assert_eq!(format_uptime(90_060, opts), "1 day, 1 hour, 1 minute");
+        assert_eq!(format_uptime(7_200, opts), "2 hours");
+        assert_eq!(format_uptime(86_459, opts), "1 day");
+
+        // Multiple years are still counted in days.
+        assert_eq!(format_uptime(63_072_000, opts), "730 days");
+    }
+
+    #[test]
+    fn test_format_uptime_abbreviated() {
+        let opts = UptimeFormat {
+            abbreviated: true,
+            minutes: false,
+            ..Default::default()
+        };
+        assert_eq!(format_uptime(273_900, opts), "3d 4h");
+        assert_eq!(format_uptime(3_599, opts), "0h");
+
+        let opts = UptimeFormat {
+            abbreviated: true,
+            seconds: true,
+            ..Default::default()
+        };
+        assert_eq!(format_uptime(273_901, opts), "3d 4h 5m 1s");
+    }
+
+    #[test]
+    fn test_format_uptime_short() {
+        let opts = UptimeFormat::default();
+        assert_eq!(format_uptime(0, opts), "0 minutes");
+        assert_eq!(format_uptime(59, opts), "0 minutes");
+
+        let opts = UptimeFormat {
+            seconds: true,
+            ..Default::default()
+        };
+        assert_eq!(format_uptime(0, opts), "0 seconds");
+        assert_eq!(format_uptime(1, opts), "1 second");
+        assert_eq!(format_uptime(61, opts), "1 minute, 1 second");
+
+        // Seconds aren't shown without the minutes before them.
+        let opts = UptimeFormat {
+            minutes: false,
+            seconds: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            format_uptime(273_901, opts),
+            "3 days, 4 hours, 5 minutes, 1 second"
+        );
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_locale_to_language_tag() {
+        assert_eq!(
+            locale_to_language_tag("en_US.UTF-8"),
+            Some(String::from("en-US"))
+        );
+        assert_eq!(
+            locale_to_language_tag("de_DE@euro"),
+            Some(String::from("de-DE"))
+        );
+        assert_eq!(locale_to_language_tag("fr"), Some(String::from("fr")));
+        assert_eq!(locale_to_language_tag("C.UTF-8"), None);
+        assert_eq!(locale_to_language_tag("POSIX"), None);
+    }
+}
@@ -0,0 +1,320 @@
+//! This module contains the types returned by [`collect_all`](crate::collect_all), which hold
+//! the values of every readout at the time they were collected.
+//!
+//! Each value is kept alongside the error that prevented it from being read, so that callers
+//! can tell a metric that isn't available apart from one that failed.
+
+use crate::traits::*;
+
+type Readout<T> = Result<T, ReadoutError>;
+
+/// Waits for the given thread to finish, a panicking readout being a bug in the library
+/// which is passed on to the caller.
+pub(crate) fn join<T>(handle: std::thread::ScopedJoinHandle<'_, T>) -> T {
+    match handle.join() {
+        Ok(value) => value,
+        Err(panic) => std::panic::resume_unwind(panic),
+    }
+}
+
+/// Holds every readout collected from the host.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ReadoutSnapshot {
+    pub battery: BatterySnapshot,
+    pub kernel: KernelSnapshot,
+    pub memory: MemorySnapshot,
+    pub general: GeneralSnapshot,
+    pub product: ProductSnapshot,
+    pub packages: Vec<(PackageManager, usize)>,
+    pub network: NetworkSnapshot,
+}
+
+/// Holds the values of the [`BatteryReadout`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct BatterySnapshot {
+    pub percentage: Readout<u8>,
+    pub status: Readout<BatteryState>,
+    pub health: Readout<u8>,
+}
+
+impl BatterySnapshot {
+    pub(crate) fn collect() -> Self {
+        let battery = crate::BatteryReadout::new();
+
+        BatterySnapshot {
+            percentage: battery.percentage(),
+            status: battery.status(),
+            health: battery.health(),
+        }
+    }
+}
+
+impl std::fmt::Display for BatterySnapshot {
+    /// Formats the battery's state, _e.g._ `Battery: 84% Charging, health 91%`, leaving out
+    /// the values that couldn't be read.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Ok(percentage) = self.percentage else {
+            return write!(f, "Battery: unavailable");
+        };
+
+        write!(f, "Battery: {percentage}%")?;
+
+        if let Ok(status) = &self.status {
+            write!(f, " {status}")?;
+        }
+
+        if let Ok(health) = self.health {
+            write!(f, ", health {health}%")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Holds the values of the [`KernelReadout`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct KernelSnapshot {
+    pub os_release: Readout<String>,
+    pub os_type: Readout<String>,
+    pub pretty_kernel: Readout<String>,
+}
+
+impl KernelSnapshot {
+    pub(crate) fn collect() -> Self {
+        let kernel = crate::KernelReadout::new();
+
+        KernelSnapshot {
+            os_release: kernel.os_release(),
+            os_type: kernel.os_type(),
+            pretty_kernel: kernel.pretty_kernel(),
+        }
+    }
+}
+
+/// Holds the values of the [`MemoryReadout`], in kilobytes.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct MemorySnapshot {
+    pub total: Readout<u64>,
+    pub free: Readout<u64>,
+    pub used: Readout<u64>,
+    pub swap_total: Readout<u64>,
+    pub swap_used: Readout<u64>,
+}
+
+impl MemorySnapshot {
+    pub(crate) fn collect() -> Self {
+        let memory = crate::MemoryReadout::new();
+
+        MemorySnapshot {
+            total: memory.total(),
+            free: memory.free(),
+            used: memory.used(),
+            swap_total: memory.swap_total(),
+            swap_used: memory.swap_used(),
+        }
+    }
+}
+
+impl std::fmt::Display for MemorySnapshot {
+    /// Formats the memory usage, _e.g._ `Mem: 12.3/16.0 GiB (77%)`, followed by the swap
+    /// usage if the host has any swap space.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The values are given in kilobytes.
+        fn usage(used: u64, total: u64) -> String {
+            const KIB_PER_GIB: f64 = 1024.0 * 1024.0;
+
+            format!(
+                "{:.1}/{:.1} GiB ({}%)",
+                used as f64 / KIB_PER_GIB,
+                total as f64 / KIB_PER_GIB,
+                (used as f64 / total as f64 * 100.0).round() as u64
+            )
+        }
+
+        match (&self.used, &self.total) {
+            (Ok(used), Ok(total)) if *total > 0 => write!(f, "Mem: {}", usage(*used, *total))?,
+            _ => write!(f, "Mem: unavailable")?,
+        }
+
+        if let (Ok(used), Ok(total)) = (&self.swap_used, &self.swap_total) {
+            if *total > 0 {
+                write!(f, ", Swap: {}", usage(*used, *total))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Holds the values of the [`GeneralReadout`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct GeneralSnapshot {
+    pub username: Readout<String>,
+    pub hostname: Readout<String>,
+    pub distribution: Readout<String>,
+    pub desktop_environment: Readout<String>,
+    pub session: Readout<String>,
+    pub window_manager: Readout<String>,
+    pub terminal: Readout<String>,
+    pub shell: Readout<String>,
+    pub resolution: Readout<String>,
+    pub backlight: Readout<usize>,
+    pub cpu_model_name: Readout<String>,
+    pub cpu_usage: Readout<usize>,
+    pub cpu_physical_cores: Readout<usize>,
+    pub cpu_cores: Readout<usize>,
+    pub uptime: Readout<usize>,
+    pub machine: Readout<String>,
+    pub os_name: Readout<String>,
+    pub disk_space: Readout<(u64, u64)>,
+    pub gpus: Readout<Vec<String>>,
+}
+
+impl GeneralSnapshot {
+    pub(crate) fn collect() -> Self {
+        // Walking the process tree and looking up GPUs are the slowest of these readouts,
+        // they're read on threads of their own while the others are read on this one.
+        std::thread::scope(|scope| {
+            let processes = scope.spawn(|| {
+                let general = crate::GeneralReadout::new();
+                (
+                    general.desktop_environment(),
+                    general.session(),
+                    general.window_manager(),
+                    general.terminal(),
+                    general.shell(ShellFormat::Relative, ShellKind::Current),
+                )
+            });
+            let gpus = scope.spawn(|| crate::GeneralReadout::new().gpus());
+
+            let general = crate::GeneralReadout::new();
+            let username = general.username();
+            let hostname = general.hostname();
+            let distribution = general.distribution();
+            let resolution = general.resolution();
+            let backlight = general.backlight();
+            let cpu_model_name = general.cpu_model_name();
+            let cpu_usage = general.cpu_usage();
+            let cpu_physical_cores = general.cpu_physical_cores();
+            let cpu_cores = general.cpu_cores();
+            let uptime = general.uptime();
+            let machine = general.machine();
+            let os_name = general.os_name();
+            let disk_space = general.disk_space();
+            let (desktop_environment, session, window_manager, terminal, shell) = join(processes);
+
+            GeneralSnapshot {
+                username,
+                hostname,
+                distribution,
+                desktop_environment,
+                session,
+                window_manager,
+                terminal,
+                shell,
+                resolution,
+                backlight,
+                cpu_model_name,
+                cpu_usage,
+                cpu_physical_cores,
+                cpu_cores,
+                uptime,
+                machine,
+                os_name,
+                disk_space,
+                gpus: join(gpus),
+            }
+        })
+    }
+}
+
+/// Holds the values of the [`ProductReadout`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ProductSnapshot {
+    pub vendor: Readout<String>,
+    pub family: Readout<String>,
+    pub product: Readout<String>,
+}
+
+impl ProductSnapshot {
+    // OpenWrt doesn't provide any product information.
+    #[cfg(feature = "openwrt")]
+    pub(crate) fn collect() -> Self {
+        ProductSnapshot {
+            vendor: Err(ReadoutError::NotImplemented),
+            family: Err(ReadoutError::NotImplemented),
+            product: Err(ReadoutError::NotImplemented),
+        }
+    }
+
+    #[cfg(not(feature = "openwrt"))]
+    pub(crate) fn collect() -> Self {
+        let product = crate::ProductReadout::new();
+
+        ProductSnapshot {
+            vendor: product.vendor(),
+            family: product.family(),
+            product: product.product(),
+        }
+    }
+}
+
+/// Holds the values of the [`NetworkReadout`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct NetworkSnapshot {
+    pub interfaces: Readout<Vec<NetworkInterface>>,
+}
+
+impl NetworkSnapshot {
+    pub(crate) fn collect() -> Self {
+        let network = crate::NetworkReadout::new();
+
+        NetworkSnapshot {
+            interfaces: network.interfaces(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_snapshot_display() {
+        let memory = MemorySnapshot {
+            total: Ok(16 * 1024 * 1024),
+            free: Ok(3_774_874),
+            used: Ok(12_897_485),
+            swap_total: Ok(0),
+            swap_used: Ok(0),
+        };
+
+        assert_eq!(memory.to_string(), "Mem: 12.3/16.0 GiB (77%)");
+    }
+
+    #[test]
+    fn test_battery_snapshot_display() {
+        let battery = BatterySnapshot {
+            percentage: Ok(84),
+            status: Ok(BatteryState::Charging),
+            health: Ok(91),
+        };
+
+        assert_eq!(battery.to_string(), "Battery: 84% Charging, health 91%");
+
+        let battery = BatterySnapshot {
+            percentage: Err(ReadoutError::MetricNotAvailable),
+            status: Err(ReadoutError::MetricNotAvailable),
+            health: Err(ReadoutError::MetricNotAvailable),
+        };
+
+        assert_eq!(battery.to_string(), "Battery: unavailable");
+    }
+}
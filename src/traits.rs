@@ -4,6 +4,7 @@
 
 /// This enum contains possible error types when doing sensor & variable readouts.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub enum ReadoutError {
     /// A specific metric might not be available on all systems (e. g. battery percentage on a
     /// desktop). \
@@ -20,6 +21,14 @@ pub enum ReadoutError {
     /// Getting a readout on a specific operating system might not make sense or causes some other
     /// kind of warning. This is not necessarily an error.
     Warning(String),
+
+    /// The readout requires privileges that the current user does not have, _e.g._ reading
+    /// the health of a disk as an unprivileged user.
+    PermissionDenied(String),
+
+    /// The readout failed for a reason that may not persist, _e.g._ a query that timed out or
+    /// a service that was too busy to answer, so retrying it later might succeed.
+    Transient(String),
 }
 
 impl std::fmt::Display for ReadoutError {
@@ -33,6 +42,8 @@ impl std::fmt::Display for ReadoutError {
             }
             ReadoutError::Other(s) => write!(f, "{}", s),
             ReadoutError::Warning(s) => write!(f, "{}", s),
+            ReadoutError::PermissionDenied(s) => write!(f, "{}", s),
+            ReadoutError::Transient(s) => write!(f, "{}", s),
         }
     }
 }
@@ -43,6 +54,65 @@ impl From<&ReadoutError> for ReadoutError {
     }
 }
 
+impl ReadoutError {
+    /**
+    Returns the category this error falls into, allowing callers to decide whether a
+    failed readout should be skipped, retried or reported without having to inspect the
+    message held by `ReadoutError::Other`.
+
+    # Example
+
+    ```
+    use libmacchina::traits::{ReadoutError, ReadoutErrorKind};
+
+    let error = ReadoutError::NotImplemented;
+    let exit_code = match error.kind() {
+        ReadoutErrorKind::Unsupported | ReadoutErrorKind::NotFound => 0,
+        ReadoutErrorKind::Transient => 75,
+        ReadoutErrorKind::Permission => 77,
+        _ => 1,
+    };
+
+    assert_eq!(exit_code, 0);
+    ```
+    */
+    pub fn kind(&self) -> ReadoutErrorKind {
+        match self {
+            ReadoutError::MetricNotAvailable => ReadoutErrorKind::NotFound,
+            ReadoutError::NotImplemented | ReadoutError::Warning(_) => {
+                ReadoutErrorKind::Unsupported
+            }
+            ReadoutError::PermissionDenied(_) => ReadoutErrorKind::Permission,
+            ReadoutError::Transient(_) => ReadoutErrorKind::Transient,
+            ReadoutError::Other(_) => ReadoutErrorKind::Internal,
+        }
+    }
+}
+
+/// The broad categories a `ReadoutError` can be sorted into, see `ReadoutError::kind`.
+///
+/// Not every category is produced by every platform, but callers should handle all of them
+/// so that they keep working as readouts start reporting more specific errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ReadoutErrorKind {
+    /// The readout is not available on this platform, or is not implemented by libmacchina.
+    Unsupported,
+
+    /// The readout requires privileges that the current user does not have.
+    Permission,
+
+    /// The requested value does not exist on this system, e.g. the battery percentage of a
+    /// desktop computer.
+    NotFound,
+
+    /// The readout failed for a reason that may not persist, retrying it might succeed.
+    Transient,
+
+    /// The readout failed for any other reason.
+    Internal,
+}
+
 /**
 This trait provides the necessary functions for querying battery statistics from the host
 computer. A desktop computer might not be able to provide values such as `percentage` and
@@ -89,13 +159,75 @@ pub trait BatteryReadout {
     /// a u8 in the range of `0` to `100`.
     fn percentage(&self) -> Result<u8, ReadoutError>;
 
+    /// This function is used for querying the current battery percentage with a finer
+    /// precision than [`BatteryReadout::percentage`], which some platforms round to whole, or
+    /// even coarser, steps. The expected value is a f32 in the range of `0.0` to `100.0`.
+    ///
+    /// _e.g._ `72.37`
+    ///
+    /// The default implementation computes it from [`BatteryReadout::energy_now`] and
+    /// [`BatteryReadout::energy_full`], falling back to [`BatteryReadout::percentage`] when
+    /// those aren't available. On Windows, this returns the same value as
+    /// [`BatteryReadout::percentage`].
+    fn percentage_precise(&self) -> Result<f32, ReadoutError> {
+        match (self.energy_now(), self.energy_full()) {
+            (Ok(now), Ok(full)) if full > 0 => Ok((now as f32 / full as f32 * 100.0).min(100.0)),
+            _ => self.percentage().map(f32::from),
+        }
+    }
+
     /// This function is used for querying the current battery charging state. If the battery is
-    /// currently being charged, we expect a return value of `BatteryState::Charging`, otherwise
+    /// currently being charged, we expect a return value of `BatteryState::Charging`, or
+    /// `BatteryState::Full` once it's fully charged while on AC power, otherwise
     /// `BatteryState::Discharging`.
     fn status(&self) -> Result<BatteryState, ReadoutError>;
 
     /// This function is used for querying the current battery's health in percentage.
     fn health(&self) -> Result<u8, ReadoutError>;
+
+    /// This function is used for checking whether the host has a battery at all, and should be
+    /// used to decide whether querying any of the other battery readouts is worthwhile.
+    ///
+    /// The default implementation considers the battery present if its percentage can be read.
+    fn is_present(&self) -> bool {
+        self.percentage().is_ok()
+    }
+
+    /// This function is used for querying the name of the battery's manufacturer.
+    ///
+    /// _e.g._ `SMP`
+    fn manufacturer(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function is used for querying the battery's model name.
+    ///
+    /// _e.g._ `5B10W13930`
+    fn model(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function is used for querying the energy currently stored in the battery, in
+    /// microwatt-hours.
+    ///
+    /// _e.g._ `41250000`
+    ///
+    /// Batteries that only report their charge, in microampere-hours, have it converted using
+    /// their design voltage.
+    fn energy_now(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function is used for querying the energy the battery holds once fully charged, in
+    /// microwatt-hours.
+    ///
+    /// _e.g._ `57000000`
+    ///
+    /// Batteries that only report their charge, in microampere-hours, have it converted using
+    /// their design voltage.
+    fn energy_full(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
 }
 
 /**
@@ -131,7 +263,9 @@ pub trait KernelReadout {
     /// Creates a new instance of the structure which implements this trait.
     fn new() -> Self;
 
-    /// This function should return the version of the kernel (e. g. `20.3.0` on macOS for Darwin).
+    /// This function should return the version of the kernel, as it reports it, _e.g._
+    /// `6.8.0-31-generic` on Linux. On macOS, it returns the version of the operating system
+    /// built on top of Darwin, _e.g._ `14.4.1`, which is what gets updated.
     fn os_release(&self) -> Result<String, ReadoutError>;
 
     /// This function should return the kernel name as a string (e. g. `Darwin` on macOS).
@@ -148,6 +282,58 @@ pub trait KernelReadout {
 
         Err(ReadoutError::MetricNotAvailable)
     }
+
+    /// This function should return the parameters the kernel was booted with,
+    /// exactly as they were passed by the bootloader.
+    ///
+    /// _e.g._ `BOOT_IMAGE=/boot/vmlinuz-linux root=/dev/sda2 rw quiet`
+    fn kernel_parameters(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the parameters the kernel was booted with, keyed by their
+    /// name, along with their value for those that have one, _e.g._ `mitigations=off` as
+    /// opposed to `quiet`.
+    ///
+    /// Values may be quoted to hold spaces, in which case the quotes are left out. A parameter
+    /// given more than once keeps its last value, and the arguments following `--` are left
+    /// out as they're meant for init.
+    fn kernel_parameters_map(
+        &self,
+    ) -> Result<std::collections::HashMap<String, Option<String>>, ReadoutError> {
+        let cmdline = self.kernel_parameters()?;
+        let mut parameters = std::collections::HashMap::new();
+        let mut parameter = String::new();
+        let mut quoted = false;
+
+        // Whitespace only separates parameters outside of quotes.
+        for c in cmdline.chars().chain(std::iter::once(' ')) {
+            match c {
+                '"' => quoted = !quoted,
+                c if c.is_whitespace() && !quoted => {
+                    if parameter.is_empty() {
+                        continue;
+                    }
+
+                    // Whatever follows is passed on to init.
+                    if parameter == "--" {
+                        break;
+                    }
+
+                    match parameter.split_once('=') {
+                        Some((name, value)) => {
+                            parameters.insert(name.to_string(), Some(value.to_string()))
+                        }
+                        None => parameters.insert(parameter.clone(), None),
+                    };
+                    parameter.clear();
+                }
+                c => parameter.push(c),
+            }
+        }
+
+        Ok(parameters)
+    }
 }
 
 /**
@@ -222,6 +408,56 @@ pub trait MemoryReadout {
 
     /// This function should return the amount of currently used memory in kilobytes.
     fn used(&self) -> Result<u64, ReadoutError>;
+
+    /// This function should return the total amount of swap space in kilobytes.
+    fn swap_total(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the amount of currently used swap space in kilobytes.
+    fn swap_used(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the number of pages swapped in and out since boot, in
+    /// that order.
+    ///
+    /// _e.g._ `(1024, 4096)`
+    ///
+    /// Sampling it twice and dividing the difference by the time elapsed in between gives
+    /// the rate at which the host is swapping, a sign of memory pressure.
+    fn swap_io(&self) -> Result<(u64, u64), ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the memory limit of the control group the current
+    /// process runs in, in kilobytes, which is what matters inside a container rather than
+    /// [MemoryReadout::total].
+    ///
+    /// _e.g._ `2097152`
+    ///
+    /// An error is returned when no limit is set.
+    fn memory_limit(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the swap limit of the control group the current process
+    /// runs in, in kilobytes.
+    ///
+    /// _e.g._ `1048576`
+    ///
+    /// An error is returned when no limit is set.
+    fn swap_limit(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the value of the kernel's virtual memory tunable with the
+    /// given `name`.
+    ///
+    /// _e.g._ `60` for `swappiness`
+    fn vm_tunable(&self, _name: &str) -> Result<i64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
 }
 
 /**
@@ -256,6 +492,37 @@ pub trait PackageReadout {
     fn count_pkgs(&self) -> Vec<(PackageManager, usize)> {
         Vec::new()
     }
+
+    /// This function should return the number of package updates that are available but
+    /// not yet installed, as reported by the host's package manager.
+    ///
+    /// _e.g._ `42`
+    ///
+    /// Finding out about updates can be slow and may reach out to the network, which is why
+    /// [PackageReadout::count_pkgs] never does so; this function has to be called explicitly.
+    fn available_updates(&self) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function counts the packages `manager` installed in `dir`, for setups where
+    /// they aren't found at their usual location, _e.g._ a Scoop installation living on
+    /// another drive.
+    ///
+    /// Every entry of the directory counts as a package, except for the one package
+    /// managers such as Scoop keep for themselves.
+    fn count_custom(&self, manager: PackageManager, dir: &std::path::Path) -> Option<usize> {
+        let entries = std::fs::read_dir(dir).ok()?.count();
+
+        let count = match manager {
+            PackageManager::Scoop => entries.saturating_sub(1),
+            _ => entries,
+        };
+
+        match count {
+            0 => None,
+            count => Some(count),
+        }
+    }
 }
 
 /**
@@ -321,6 +588,30 @@ pub trait NetworkReadout {
     /// received by the interface of the host.
     fn rx_packets(&self, interface: Option<&str>) -> Result<usize, ReadoutError>;
 
+    /// This function should return the number of packets
+    /// dropped while being transmitted by the interface of the host.
+    fn tx_dropped(&self, _interface: Option<&str>) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the number of packets
+    /// dropped while being received by the interface of the host.
+    fn rx_dropped(&self, _interface: Option<&str>) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the number of errors
+    /// encountered while transmitting through the interface of the host.
+    fn tx_errors(&self, _interface: Option<&str>) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the number of errors
+    /// encountered while receiving through the interface of the host.
+    fn rx_errors(&self, _interface: Option<&str>) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
     /// This function should return the logical address, i.e. _local IPv4/6 address_ of the
     /// specified interface.
     ///
@@ -332,6 +623,103 @@ pub trait NetworkReadout {
     ///
     /// _e.g._ `52:9a:d2:d3:b5:fd`
     fn physical_address(&self, interface: Option<&str>) -> Result<String, ReadoutError>;
+
+    /// This function should return the IPv4 broadcast address of the specified interface.
+    ///
+    /// _e.g._ `192.168.1.255`
+    ///
+    /// IPv6 doesn't have broadcast addresses, interfaces without an IPv4 address result in
+    /// [ReadoutError::MetricNotAvailable].
+    fn broadcast_address(&self, _interface: Option<&str>) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the IPv4 subnet mask of the specified interface.
+    ///
+    /// _e.g._ `255.255.255.0`
+    ///
+    /// IPv6 uses prefix lengths instead, interfaces without an IPv4 address result in
+    /// [ReadoutError::MetricNotAvailable].
+    fn subnet_mask(&self, _interface: Option<&str>) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return every network interface of the host, along with the
+    /// information gathered about each of them in a single pass.
+    fn interfaces(&self) -> Result<Vec<NetworkInterface>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the number of TCP sockets, over IPv4 and IPv6, whatever
+    /// their state, listening sockets included.
+    ///
+    /// _e.g._ `87`
+    fn tcp_connection_count(&self) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the number of established TCP connections, over IPv4 and
+    /// IPv6.
+    ///
+    /// _e.g._ `23`
+    fn established_tcp_connection_count(&self) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return whether a VPN, or another kind of tunnel, is active,
+    /// _i.e._ whether any of the host's tunnel interfaces is up.
+    ///
+    /// _e.g._ `true` while connected through WireGuard
+    fn vpn_active(&self) -> Result<bool, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function returns whether the host can reach the internet, as opposed to only
+    /// its local network, by attempting to connect to a well-known public host.
+    ///
+    /// It is a shortcut for [NetworkReadout::can_reach] probing Cloudflare's DNS resolver,
+    /// and takes up to two seconds to answer when the host is offline.
+    #[cfg(feature = "network-queries")]
+    fn has_internet(&self) -> Result<bool, ReadoutError> {
+        let target = std::net::SocketAddr::from(([1, 1, 1, 1], 443));
+        self.can_reach(target, std::time::Duration::from_secs(2))
+    }
+
+    /// This function returns whether a TCP connection to `target` can be established within
+    /// `timeout`.
+    ///
+    /// The target is given as an address rather than a host name, so that the probe
+    /// doesn't depend on a DNS server being reachable, and fails fast when there's no route
+    /// to it.
+    #[cfg(feature = "network-queries")]
+    fn can_reach(
+        &self,
+        target: std::net::SocketAddr,
+        timeout: std::time::Duration,
+    ) -> Result<bool, ReadoutError> {
+        Ok(std::net::TcpStream::connect_timeout(&target, timeout).is_ok())
+    }
+}
+
+/// Holds the information gathered about a single network interface.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkInterface {
+    /// The name of the interface, _e.g._ `eth0` or `Ethernet`.
+    pub name: String,
+    /// The first IPv4 address assigned to the interface.
+    pub ipv4: Option<String>,
+    /// The first IPv6 address assigned to the interface.
+    pub ipv6: Option<String>,
+    /// The physical address, i.e. _MAC address_ of the interface.
+    pub mac: Option<String>,
+    /// The number of bytes transmitted through the interface.
+    pub tx_bytes: Option<usize>,
+    /// The number of bytes received through the interface.
+    pub rx_bytes: Option<usize>,
+    /// Whether the interface is up.
+    pub is_up: bool,
+    /// The maximum transmission unit of the interface, in bytes.
+    pub mtu: Option<u32>,
 }
 
 /**
@@ -389,6 +777,44 @@ pub trait ProductReadout {
     ///
     /// This is set by the machine's manufacturer.
     fn product(&self) -> Result<String, ReadoutError>;
+
+    /// This function should return the name the host's machine is marketed under, for
+    /// machines whose product name is a model identifier.
+    ///
+    /// _e.g._ `MacBook Pro (14-inch, 2021)` for `MacBookPro18,3`
+    fn product_marketing_name(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the serial number of the host's machine.
+    ///
+    /// _e.g._ `PF2ABCDE`
+    ///
+    /// This is set by the machine's manufacturer, and reading it may require elevated
+    /// privileges.
+    fn serial_number(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the form factor of the host's machine, which is one of
+    /// `Desktop`, `Laptop`, `Tablet`, `Handheld` or `Server`.
+    ///
+    /// _e.g._ `Laptop`
+    ///
+    /// This is set by the machine's manufacturer.
+    fn form_factor(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the release date of the host's firmware, as reported by
+    /// the firmware itself.
+    ///
+    /// _e.g._ `09/14/2023`
+    ///
+    /// The date is returned as-is, manufacturers don't all format it the same way.
+    fn bios_date(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
 }
 
 /**
@@ -521,6 +947,62 @@ pub trait GeneralReadout {
     /// _e.g._ `Arch Linux`
     fn distribution(&self) -> Result<String, ReadoutError>;
 
+    /// This function should return the codename of the release of the distribution.
+    ///
+    /// _e.g._ `bookworm`
+    fn os_codename(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the version of SteamOS the host is running, _e.g._ on a
+    /// Steam Deck.
+    ///
+    /// _e.g._ `3.5.7`
+    fn steamos_version(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the name of the icon the distribution uses as its logo,
+    /// as declared by the `LOGO` field of `os-release`, or its `ID` when it doesn't declare
+    /// one.
+    ///
+    /// _e.g._ `archlinux-logo`
+    fn os_logo_name(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return whether _System Integrity Protection_ is enabled on
+    /// the host, which only applies to macOS.
+    ///
+    /// _e.g._ `true`
+    fn system_integrity_protection(&self) -> Result<bool, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return whether the current process is being translated by
+    /// _Rosetta 2_, which only applies to macOS.
+    ///
+    /// _e.g._ `false`
+    fn macos_rosetta(&self) -> Result<bool, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the version of the Windows Subsystem for Linux the host
+    /// is running under, _i.e._ `1` or `2`.
+    ///
+    /// _e.g._ `2`
+    fn wsl_version(&self) -> Result<u8, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the name of the bootloader used by the host, along
+    /// with its version if it's readily available.
+    ///
+    /// _e.g._ `systemd-boot 254.1`
+    fn bootloader(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
     /// This function should return the name of the used desktop environment.
     ///
     /// _e.g._ `Plasma`
@@ -528,7 +1010,7 @@ pub trait GeneralReadout {
 
     /// This function should return the type of session that's in use.
     ///
-    /// _e.g._ `Wayland`
+    /// _e.g._ `Wayland`, or on Windows, `Console` or `RDP` for a remote desktop session
     fn session(&self) -> Result<String, ReadoutError>;
 
     /// This function should return the name of the used window manager.
@@ -536,6 +1018,30 @@ pub trait GeneralReadout {
     /// _e.g._ `KWin`
     fn window_manager(&self) -> Result<String, ReadoutError>;
 
+    /// This function should return the name of the Wayland compositor the session is
+    /// running on.
+    ///
+    /// _e.g._ `Hyprland`
+    fn wayland_compositor(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the name and version of the display server, or of the
+    /// _Wayland_ compositor, the current session is running on.
+    ///
+    /// _e.g._ `X.Org 1.21.1.8`
+    fn display_server_version(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the names of the users that are currently logged in, with
+    /// users that have multiple sessions listed once.
+    ///
+    /// _e.g._ `["root", "taylor"]`
+    fn logged_in_user_names(&self) -> Result<Vec<String>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
     /// This function should return the name of the used terminal emulator.
     ///
     /// _e.g._ `kitty`
@@ -552,9 +1058,34 @@ pub trait GeneralReadout {
 
     _e.g._ /bin/bash, /bin/zsh, etc.
     */
-
     fn shell(&self, _shorthand: ShellFormat, kind: ShellKind) -> Result<String, ReadoutError>;
 
+    /// This function should return the path to the configuration file of the current shell,
+    /// falling back to the user's default shell when the current one can't be determined.
+    ///
+    /// _e.g._ `/home/user/.bashrc`
+    ///
+    /// `bash`, `zsh` and `fish` are recognized, any other shell results in
+    /// [ReadoutError::NotImplemented].
+    fn shell_config_path(&self) -> Result<std::path::PathBuf, ReadoutError> {
+        let shell = self
+            .shell(ShellFormat::Relative, ShellKind::Current)
+            .or_else(|_| self.shell(ShellFormat::Relative, ShellKind::Default))?;
+        let home = home::home_dir().ok_or(ReadoutError::MetricNotAvailable)?;
+
+        match shell.trim() {
+            "bash" => Ok(home.join(".bashrc")),
+            "zsh" => Ok(std::env::var_os("ZDOTDIR")
+                .map_or(home, std::path::PathBuf::from)
+                .join(".zshrc")),
+            "fish" => Ok(std::env::var_os("XDG_CONFIG_HOME")
+                .map_or_else(|| home.join(".config"), std::path::PathBuf::from)
+                .join("fish")
+                .join("config.fish")),
+            _ => Err(ReadoutError::NotImplemented),
+        }
+    }
+
     /// This function should return the model name of the CPU \
     ///
     /// _e.g._ `Intel(R) Core(TM) i5-8265U CPU @ 1.60GHz`
@@ -563,12 +1094,58 @@ pub trait GeneralReadout {
     /// This function should return the average CPU usage over the last minute.
     fn cpu_usage(&self) -> Result<usize, ReadoutError>;
 
+    /// This function should return the CPU usage in percent, measured by sampling the time the
+    /// CPU spent busy over the given `interval`. The calling thread is blocked while sampling.
+    ///
+    /// Shorter intervals return sooner, but fewer scheduler ticks fall within them, making the
+    /// result noisier: a status bar might get away with `100ms`, a benchmark would want `1s`.
+    fn cpu_usage_sampled(&self, _interval: std::time::Duration) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return how the time of the host's processors was split between
+    /// each kind of work, measured over the given `interval`. The calling thread is blocked
+    /// while sampling.
+    ///
+    /// Unlike [`cpu_usage_sampled`](GeneralReadout::cpu_usage_sampled), it lets the caller
+    /// decide whether time spent waiting on I/O or stolen by the hypervisor counts as busy.
+    fn cpu_usage_breakdown(
+        &self,
+        _interval: std::time::Duration,
+    ) -> Result<CpuUsageBreakdown, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
     /// This function should return the number of physical cores of the host's processor.
     fn cpu_physical_cores(&self) -> Result<usize, ReadoutError>;
 
     /// This function should return the number of logical cores of the host's processor.
     fn cpu_cores(&self) -> Result<usize, ReadoutError>;
 
+    /// This function should return whether simultaneous multithreading, _e.g._
+    /// Hyper-Threading, is enabled on the host's processor.
+    ///
+    /// Processors supporting it, on which it has been disabled, _e.g._ from the firmware
+    /// settings, are reported as `false`.
+    fn cpu_hyper_threading(&self) -> Result<bool, ReadoutError> {
+        Ok(self.cpu_cores()? > self.cpu_physical_cores()?)
+    }
+
+    /// This function should return how the host's logical processors are laid out across
+    /// sockets and cores, which tells more than [GeneralReadout::cpu_physical_cores] and
+    /// [GeneralReadout::cpu_cores] do on machines with several processors.
+    fn cpu_topology(&self) -> Result<CpuTopology, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the maximum frequency of the host's processor in MHz,
+    /// which may exceed its base frequency when the processor is able to boost.
+    ///
+    /// _e.g._ `4900`
+    fn cpu_max_frequency(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
     /// This function should return the uptime of the OS in seconds.
     fn uptime(&self) -> Result<usize, ReadoutError>;
 
@@ -588,14 +1165,521 @@ pub trait GeneralReadout {
     /// _e.g._ '(50000000, 1000000000)'
     fn disk_space(&self) -> Result<(u64, u64), ReadoutError>;
 
+    /// This function should return a tuple with the number of bytes read from and written to
+    /// the host's disks since it booted.
+    ///
+    /// Sampling it twice gives the throughput over the time in between.
+    ///
+    /// _e.g._ `(18000000000, 42000000000)`
+    fn disk_io(&self) -> Result<(u64, u64), ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the removable drives, such as USB sticks and memory
+    /// cards, currently mounted on the host.
+    ///
+    /// An empty list is returned when there aren't any.
+    fn removable_drives(&self) -> Result<Vec<DriveInfo>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
     /// This function should return the device names of any _GPU(s)_ connected to the host machine.
     fn gpus(&self) -> Result<Vec<String>, ReadoutError>;
+
+    /// This function should return the number of _GPU(s)_ connected to the host machine.
+    ///
+    /// Implementations should prefer a cheaper source than the one used by
+    /// [GeneralReadout::gpus] when possible, which this defaults to.
+    fn gpu_count(&self) -> Result<usize, ReadoutError> {
+        Ok(self.gpus()?.len())
+    }
+
+    /// This function should return the temperature of any _GPU(s)_ connected to the host
+    /// machine in degrees Celsius, alongside their device names.
+    ///
+    /// _e.g._ `[("Radeon RX 6800 XT", 54.0)]`
+    fn gpu_temp(&self) -> Result<Vec<(String, f32)>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function returns the temperature of every _GPU_ connected to the host machine in
+    /// degrees Celsius, in the same order as [GeneralReadout::gpu_temp].
+    ///
+    /// Unlike [GeneralReadout::gpu_temp], an empty list is returned when none of the GPUs
+    /// report their temperature.
+    ///
+    /// _e.g._ `[54.0, 61.0]`
+    fn gpu_temperature(&self) -> Result<Vec<f32>, ReadoutError> {
+        match self.gpu_temp() {
+            Ok(temperatures) => Ok(temperatures.into_iter().map(|(_, temp)| temp).collect()),
+            Err(ReadoutError::MetricNotAvailable) => Ok(vec![]),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// This function should return the kernel driver bound to each _GPU_ connected to the
+    /// host machine, alongside their device names, which tells open drivers apart from
+    /// proprietary ones.
+    ///
+    /// _e.g._ `[("GeForce RTX 3080", "nvidia")]`, or `nouveau` for the open driver
+    fn gpu_driver_name(&self) -> Result<Vec<(String, String)>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the temperature of the host machine's _CPU_ in degrees
+    /// Celsius.
+    ///
+    /// _e.g._ `48.5`
+    fn cpu_temperature(&self) -> Result<f32, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the amount of dedicated video memory of each _GPU_
+    /// connected to the host machine, in megabytes, alongside their device names.
+    ///
+    /// _e.g._ `[("Radeon RX 6800 XT", 16368)]`
+    fn gpu_vram(&self) -> Result<Vec<(String, u64)>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return whether the host's processor is currently being
+    /// throttled to keep its temperature in check.
+    fn cpu_throttled(&self) -> Result<bool, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the active keyboard layout.
+    ///
+    /// _e.g._ `us`
+    fn keyboard_layout(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the speed of the mouse pointer, on a scale from `1` to
+    /// `20`, `10` being the default.
+    ///
+    /// _e.g._ `10`
+    fn mouse_speed(&self) -> Result<u32, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the name of the default audio output device.
+    ///
+    /// _e.g._ `Built-in Audio Analog Stereo`
+    fn default_audio_output(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the versions of the _.NET_ runtimes installed on the host
+    /// machine, or an empty list if there are none.
+    ///
+    /// _e.g._ `[".NET Framework 4.8.09032", "Microsoft.NETCore.App 8.0.1"]`
+    fn dotnet_versions(&self) -> Result<Vec<String>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the name and state of every software _RAID_ array
+    /// configured on the host machine.
+    ///
+    /// _e.g._ `[("md0", "clean"), ("md1", "degraded")]`
+    fn raid_status(&self) -> Result<Vec<(String, String)>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the number of operating system updates that are
+    /// available but not yet installed.
+    ///
+    /// _e.g._ `12`
+    ///
+    /// The package lists aren't refreshed beforehand, and querying them may take a while.
+    fn pending_updates(&self) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the number of bits of entropy available in the kernel's
+    /// random number generator pool.
+    ///
+    /// _e.g._ `256`
+    fn entropy_available(&self) -> Result<u32, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return whether TRIM is performed on the solid-state drive
+    /// holding the root filesystem, be it continuously or periodically.
+    ///
+    /// _e.g._ `true`
+    fn trim_enabled(&self) -> Result<bool, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the model name of each connected display, as found in
+    /// its _EDID_.
+    ///
+    /// _e.g._ `["DELL U2720Q", "LG HDR 4K"]`
+    fn monitor_names(&self) -> Result<Vec<String>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the power the battery is being charged with, in watts, or
+    /// `0.0` when it isn't charging.
+    ///
+    /// _e.g._ `45.2`
+    fn battery_charging_speed(&self) -> Result<f32, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the information gathered about each connected monitor,
+    /// in a single pass, which spares querying their resolution, refresh rate and so on one
+    /// readout at a time.
+    fn connected_monitors_info(&self) -> Result<Vec<MonitorInfo>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the number of bits used for each pixel of the primary
+    /// display.
+    ///
+    /// _e.g._ `24`, or `30` for a display driven with 10 bits per color channel
+    fn color_depth(&self) -> Result<u8, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the language the user interface of the operating system
+    /// is displayed in, as a _BCP 47_ language tag. Unlike the locale, it doesn't affect the
+    /// formatting of dates or numbers.
+    ///
+    /// _e.g._ `en-US`
+    fn system_language(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return whether the host supports hibernation, _i.e._ the _S4_
+    /// power state, in which the content of the memory is saved to the disk before powering
+    /// off.
+    ///
+    /// _e.g._ `true`
+    fn hibernation_supported(&self) -> Result<bool, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the size of the terminal the standard output is attached
+    /// to, as a tuple of its number of columns and rows.
+    ///
+    /// _e.g._ `(120, 30)`
+    ///
+    /// An error is returned if the standard output isn't a terminal, _e.g._ when it's piped or
+    /// redirected to a file.
+    fn terminal_size(&self) -> Result<(u16, u16), ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return whether any of the connected displays is currently
+    /// driven in _HDR_ mode.
+    ///
+    /// _e.g._ `true`
+    fn screen_is_hdr(&self) -> Result<bool, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return whether a touchscreen is built into, or connected to,
+    /// the host machine.
+    ///
+    /// _e.g._ `true`
+    fn has_touchscreen(&self) -> Result<bool, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the panel technology of the primary display, as
+    /// advertised by the _DisplayID_ data of its _EDID_.
+    ///
+    /// _e.g._ `IPS`
+    ///
+    /// Few displays advertise it, in which case an error is returned rather than a guess.
+    fn display_technology(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the number of fonts installed on the host machine,
+    /// system-wide and for the current user.
+    ///
+    /// _e.g._ `312`
+    fn installed_fonts_count(&self) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the security level _Thunderbolt_ and _USB4_ devices are
+    /// authorized under before their _PCIe_ tunnels get set up.
+    ///
+    /// _e.g._ `user`, with `none`, `secure` and `dponly` being the other common levels
+    fn thunderbolt_security_level(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the model name of the disk hosting the given `path`,
+    /// prefixed with the disk's transport where it's known to be _NVMe_ or _SATA_.
+    ///
+    /// _e.g._ `NVMe Samsung SSD 980 PRO 1TB`
+    fn disk_model(&self, path: &std::path::Path) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the remaining life of the given NVMe drive, _e.g._
+    /// `/dev/nvme0` on Linux or `\\.\PhysicalDrive0` on Windows, in percentage, as
+    /// estimated by the drive itself.
+    ///
+    /// _e.g._ `97`
+    ///
+    /// Reading it usually requires root, or administrator, privileges.
+    fn nvme_health_percentage(&self, drive: &str) -> Result<u8, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the _S.M.A.R.T._ health status of the given disk, _e.g._
+    /// `/dev/sda` on Linux or `\\.\PhysicalDrive0` on Windows.
+    ///
+    /// _e.g._ `DiskHealth::Ok`
+    ///
+    /// Querying a disk's health usually requires root, or administrator, privileges.
+    fn disk_health(&self, device: &str) -> Result<DiskHealth, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function returns the total available memory in kilobytes.
+    ///
+    /// It is a shortcut for [MemoryReadout::total] using the host's memory readout.
+    fn memory_total(&self) -> Result<u64, ReadoutError> {
+        crate::MemoryReadout::new().total()
+    }
+
+    /// This function returns the amount of currently used memory in kilobytes.
+    ///
+    /// It is a shortcut for [MemoryReadout::used] using the host's memory readout.
+    fn memory_used(&self) -> Result<u64, ReadoutError> {
+        crate::MemoryReadout::new().used()
+    }
+
+    /// This function returns the total amount of swap space in kilobytes.
+    ///
+    /// It is a shortcut for [MemoryReadout::swap_total] using the host's memory readout.
+    fn swap_total(&self) -> Result<u64, ReadoutError> {
+        crate::MemoryReadout::new().swap_total()
+    }
+
+    /// This function returns the amount of currently used swap space in kilobytes.
+    ///
+    /// It is a shortcut for [MemoryReadout::swap_used] using the host's memory readout.
+    fn swap_used(&self) -> Result<u64, ReadoutError> {
+        crate::MemoryReadout::new().swap_used()
+    }
+
+    /// This function returns the number of packages installed through each package manager
+    /// found on the host.
+    ///
+    /// It is a shortcut for [PackageReadout::count_pkgs] using the host's package readout.
+    /// Every package manager gets queried, which can be slow on systems that have many of
+    /// them installed.
+    fn installed_packages_by_manager(&self) -> Vec<(PackageManager, usize)> {
+        crate::PackageReadout::new().count_pkgs()
+    }
+}
+
+/**
+This trait is implemented by readouts that gather some of their data once, when they're
+created, and hand out that same data until they're explicitly told to refresh it.
+
+Long-running programs, such as status bars, can hold on to such a readout and refresh it
+whenever they update their display, instead of creating a new one every time.
+
+# Example
+
+```
+use libmacchina::traits::Refreshable;
+
+pub struct MacOSBatteryReadout {
+    percentage: u8,
+}
+
+impl Refreshable for MacOSBatteryReadout {
+    fn refresh(&mut self) {
+        // Query the power source again...
+        self.percentage = 75;
+    }
+}
+```
+*/
+pub trait Refreshable {
+    /// Discards the data held by the readout and gathers it again.
+    fn refresh(&mut self);
+}
+
+/**
+This trait provides an interface for querying the _processes_ running on the host system.
+
+# Example
+
+```
+use libmacchina::traits::{ProcessInfo, ProcessReadout, SortKey};
+use libmacchina::traits::ReadoutError;
+
+pub struct MacOSProcessReadout;
+
+impl ProcessReadout for MacOSProcessReadout {
+    fn new() -> Self {
+        MacOSProcessReadout {}
+    }
+
+    fn top_processes(&self, n: usize, by: SortKey) -> Result<Vec<ProcessInfo>, ReadoutError> {
+        // Sample every running process and keep the n heaviest ones...
+        Ok(vec![])
+    }
+}
+```
+*/
+pub trait ProcessReadout {
+    /// Creates a new instance of the structure which implements this trait.
+    fn new() -> Self;
+
+    /// This function should return the `n` processes using the most resources, as
+    /// determined by `by`, heaviest first.
+    ///
+    /// Measuring CPU usage requires two samples, so this function blocks the calling thread
+    /// for a short interval.
+    ///
+    /// _e.g._ `[ProcessInfo { pid: 1234, name: "firefox", cpu_usage: 12.5, rss: 524288 }]`
+    fn top_processes(&self, _n: usize, _by: SortKey) -> Result<Vec<ProcessInfo>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the amount of memory used by the calling process, i.e.
+    /// its resident set size, in bytes, unlike [ProcessInfo::rss] which is in kilobytes.
+    ///
+    /// _e.g._ `4669440`
+    fn process_memory(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+}
+
+/// Holds the information gathered about a single running process.
+#[derive(Debug, Clone)]
+pub struct ProcessInfo {
+    /// The process identifier.
+    pub pid: u32,
+    /// The name of the process' executable.
+    pub name: String,
+    /// The share of a single CPU the process used while it was being sampled, in percent.
+    /// Processes running on several cores at once can exceed `100`.
+    pub cpu_usage: f32,
+    /// The resident set size of the process, in kilobytes.
+    pub rss: u64,
+}
+
+/// Holds the share of time, in percent, the host's processors spent on each kind of work.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CpuUsageBreakdown {
+    /// Time spent running processes in user mode.
+    pub user: f32,
+    /// Time spent running niced processes in user mode.
+    pub nice: f32,
+    /// Time spent running the kernel.
+    pub system: f32,
+    /// Time spent doing nothing.
+    pub idle: f32,
+    /// Time spent idle while waiting for I/O to complete.
+    pub iowait: f32,
+    /// Time spent servicing hardware interrupts.
+    pub irq: f32,
+    /// Time spent servicing software interrupts.
+    pub softirq: f32,
+    /// Time taken by the hypervisor to run other virtual machines.
+    pub steal: f32,
+}
+
+/// Holds how the host's logical processors are laid out across sockets and cores.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CpuTopology {
+    /// The number of physical processor packages.
+    pub sockets: usize,
+    /// The number of physical cores of each socket.
+    pub cores_per_socket: usize,
+    /// The number of hardware threads run by each core. Processors mixing cores with and
+    /// without simultaneous multithreading report that of the cores that have it.
+    pub threads_per_core: usize,
+    /// The total number of logical processors.
+    pub total_threads: usize,
+}
+
+/// Holds the possible criteria processes can be ranked by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SortKey {
+    Cpu,
+    Memory,
+}
+
+/// Holds the information gathered about a mounted drive.
+#[derive(Debug, Clone)]
+pub struct DriveInfo {
+    /// The location the drive is mounted at, _e.g._ `/run/media/user/USB` or `E:\`.
+    pub mount_point: String,
+    /// The label of the drive's filesystem, if it has one.
+    pub label: Option<String>,
+    /// The amount of free space on the drive, in bytes.
+    pub free: u64,
+    /// The total amount of space on the drive, in bytes.
+    pub total: u64,
+}
+
+/// Holds the information gathered about a connected monitor.
+///
+/// Numeric fields that couldn't be determined are left at `0`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MonitorInfo {
+    /// The name of the monitor, or that of the output it's connected to when it doesn't
+    /// advertise one, _e.g._ `DELL U2720Q` or `HDMI-1`.
+    pub name: String,
+    /// The width and height of the monitor's current mode, in pixels.
+    pub resolution: (u32, u32),
+    /// The refresh rate of the monitor's current mode, in hertz.
+    pub refresh_rate: u32,
+    /// Whether the monitor is the primary one.
+    pub is_primary: bool,
+    /// The number of bits used for each pixel.
+    pub color_depth: u32,
+}
+
+/// Holds the possible health statuses reported by a disk's _S.M.A.R.T._ self-assessment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DiskHealth {
+    /// The disk passed its self-assessment.
+    Ok,
+    /// The disk passed its self-assessment, but has logged errors or has attributes that
+    /// crossed their failure threshold in the past.
+    Warning,
+    /// The disk failed its self-assessment, or is predicted to fail soon.
+    Failing,
+    /// The disk's health couldn't be determined.
+    Unknown,
+}
+
+impl std::fmt::Display for DiskHealth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            DiskHealth::Ok => write!(f, "OK"),
+            DiskHealth::Warning => write!(f, "Warning"),
+            DiskHealth::Failing => write!(f, "Failing"),
+            DiskHealth::Unknown => write!(f, "Unknown"),
+        }
+    }
 }
 
 /// Holds the possible variants for battery status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum BatteryState {
     Charging,
     Discharging,
+    /// The battery is fully charged while on AC power.
+    Full,
 }
 
 impl std::fmt::Display for BatteryState {
@@ -607,6 +1691,9 @@ impl std::fmt::Display for BatteryState {
             BatteryState::Discharging => {
                 write!(f, "Discharging")
             }
+            BatteryState::Full => {
+                write!(f, "Full")
+            }
         }
     }
 }
@@ -616,6 +1703,7 @@ impl From<BatteryState> for &'static str {
         match state {
             BatteryState::Charging => "Charging",
             BatteryState::Discharging => "Discharging",
+            BatteryState::Full => "Full",
         }
     }
 }
@@ -623,6 +1711,7 @@ impl From<BatteryState> for &'static str {
 /// The currently running shell is a program, whose path
 /// can be _relative_, or _absolute_.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum ShellFormat {
     Relative,
     Absolute,
@@ -631,12 +1720,15 @@ pub enum ShellFormat {
 #[derive(Debug)]
 /// There are two distinct kinds of shells, a so called *"current"* shell, i.e. the shell the user is currently using.
 /// And a default shell, i.e. that the user sets for themselves using the `chsh` tool.
+#[non_exhaustive]
 pub enum ShellKind {
     Current,
     Default,
 }
 
 /// The supported package managers whose packages can be extracted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum PackageManager {
     Homebrew,
     MacPorts,
@@ -651,11 +1743,16 @@ pub enum PackageManager {
     Rpm,
     Cargo,
     Flatpak,
+    FlatpakRuntime,
     Snap,
     Android,
     Pkg,
     Scoop,
     Nix,
+    PowerShellGet,
+    Pnpm,
+    Bun,
+    Asdf,
 }
 
 impl std::fmt::Display for PackageManager {
@@ -674,11 +1771,116 @@ impl std::fmt::Display for PackageManager {
             PackageManager::Rpm => write!(f, "rpm"),
             PackageManager::Cargo => write!(f, "cargo"),
             PackageManager::Flatpak => write!(f, "flatpak"),
+            PackageManager::FlatpakRuntime => write!(f, "flatpak-runtime"),
             PackageManager::Snap => write!(f, "snap"),
             PackageManager::Android => write!(f, "Android"),
             PackageManager::Pkg => write!(f, "pkg"),
             PackageManager::Scoop => write!(f, "Scoop"),
             PackageManager::Nix => write!(f, "nix"),
+            PackageManager::PowerShellGet => write!(f, "PowerShellGet"),
+            PackageManager::Pnpm => write!(f, "pnpm"),
+            PackageManager::Bun => write!(f, "bun"),
+            PackageManager::Asdf => write!(f, "asdf"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readout_error_kind() {
+        let denied = ReadoutError::from(std::io::Error::from(std::io::ErrorKind::PermissionDenied));
+        assert_eq!(denied.kind(), ReadoutErrorKind::Permission);
+
+        let missing = ReadoutError::from(std::io::Error::from(std::io::ErrorKind::NotFound));
+        assert_eq!(missing.kind(), ReadoutErrorKind::Internal);
+
+        for kind in [
+            std::io::ErrorKind::Interrupted,
+            std::io::ErrorKind::TimedOut,
+            std::io::ErrorKind::WouldBlock,
+        ] {
+            let error = ReadoutError::from(std::io::Error::from(kind));
+            assert_eq!(error.kind(), ReadoutErrorKind::Transient);
+        }
+
+        assert_eq!(
+            ReadoutError::MetricNotAvailable.kind(),
+            ReadoutErrorKind::NotFound
+        );
+        assert_eq!(
+            ReadoutError::Warning(String::new()).kind(),
+            ReadoutErrorKind::Unsupported
+        );
+    }
+
+    /// Reports the given command line as the kernel's parameters.
+    struct Cmdline(&'static str);
+
+    impl KernelReadout for Cmdline {
+        fn new() -> Self {
+            Cmdline("")
+        }
+
+        fn os_release(&self) -> Result<String, ReadoutError> {
+            Err(ReadoutError::NotImplemented)
+        }
+
+        fn os_type(&self) -> Result<String, ReadoutError> {
+            Err(ReadoutError::NotImplemented)
+        }
+
+        fn kernel_parameters(&self) -> Result<String, ReadoutError> {
+            Ok(self.0.to_string())
         }
     }
+
+    fn parameters(cmdline: &'static str) -> std::collections::HashMap<String, Option<String>> {
+        Cmdline(cmdline).kernel_parameters_map().unwrap()
+    }
+
+    #[test]
+    fn test_kernel_parameters_map() {
+        let map = parameters("BOOT_IMAGE=/boot/vmlinuz-linux root=/dev/sda2 rw  quiet\n");
+        assert_eq!(map.len(), 4);
+        assert_eq!(map["root"], Some(String::from("/dev/sda2")));
+        assert_eq!(map["rw"], None);
+        assert_eq!(map["quiet"], None);
+
+        // Only the first equals sign separates the name from the value.
+        let map = parameters("rd.luks.options=discard=on");
+        assert_eq!(map["rd.luks.options"], Some(String::from("discard=on")));
+
+        // An empty value is still a value.
+        assert_eq!(parameters("console=")["console"], Some(String::new()));
+        assert!(parameters("").is_empty());
+    }
+
+    #[test]
+    fn test_kernel_parameters_map_quoted() {
+        let map = parameters(r#"dyndbg="file ec.c +p" "acpi_osi=Windows 2020" quiet"#);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["dyndbg"], Some(String::from("file ec.c +p")));
+        assert_eq!(map["acpi_osi"], Some(String::from("Windows 2020")));
+    }
+
+    #[test]
+    fn test_kernel_parameters_map_duplicates() {
+        let map = parameters("console=tty0 console=ttyS0,115200 quiet quiet");
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["console"], Some(String::from("ttyS0,115200")));
+    }
+
+    #[test]
+    fn test_kernel_parameters_map_init_arguments() {
+        let map = parameters("root=/dev/sda2 -- single splash=verbose");
+        assert_eq!(map.len(), 1);
+        assert!(!map.contains_key("single"));
+
+        // The terminator only counts as a parameter of its own.
+        let map = parameters("a--b --c");
+        assert_eq!(map.len(), 2);
+    }
 }
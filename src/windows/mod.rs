@@ -1,29 +1,132 @@
 use crate::traits::*;
 use std::collections::HashMap;
-use std::path::PathBuf;
+#[cfg(feature = "full")]
+use std::net::{IpAddr, Ipv4Addr};
+use std::os::windows::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+#[cfg(feature = "full")]
+use std::sync::OnceLock;
+#[cfg(feature = "full")]
 use winreg::enums::*;
+#[cfg(feature = "full")]
 use winreg::RegKey;
+#[cfg(feature = "full")]
 use wmi::WMIResult;
+#[cfg(feature = "full")]
 use wmi::{COMLibrary, Variant, WMIConnection};
 
 use windows::{
-    core::PSTR, Win32::System::Power::GetSystemPowerStatus,
-    Win32::System::Power::SYSTEM_POWER_STATUS,
+    core::{Interface, PCWSTR, PSTR, PWSTR},
+    Win32::Foundation::{CloseHandle, E_ACCESSDENIED, FILETIME, HANDLE},
+    Win32::Globalization::{
+        GetLocaleInfoW, GetUserDefaultUILanguage, LCIDToLocaleName, LOCALE_SLANGUAGE,
+    },
+    Win32::Graphics::Dxgi::Common::DXGI_COLOR_SPACE_RGB_FULL_G2084_NONE_P2020,
+    Win32::Graphics::Dxgi::{
+        CreateDXGIFactory1, IDXGIFactory1, IDXGIOutput6, DXGI_ADAPTER_FLAG_SOFTWARE,
+    },
+    Win32::Graphics::Gdi::{
+        EnumDisplayDevicesW, EnumDisplaySettingsW, DEVMODEW, DISPLAY_DEVICEW,
+        DISPLAY_DEVICE_ATTACHED_TO_DESKTOP, DISPLAY_DEVICE_PRIMARY_DEVICE, ENUM_CURRENT_SETTINGS,
+    },
+    Win32::Storage::FileSystem::{
+        BusTypeNvme, BusTypeSata, CreateFileW, GetDiskFreeSpaceExW, GetDriveTypeW,
+        GetLogicalDrives, GetVolumeInformationW, GetVolumePathNameW, FILE_ACCESS_FLAGS,
+        FILE_FLAGS_AND_ATTRIBUTES, FILE_GENERIC_READ, FILE_GENERIC_WRITE, FILE_SHARE_READ,
+        FILE_SHARE_WRITE, OPEN_EXISTING,
+    },
+    Win32::System::Console::{
+        GetConsoleScreenBufferInfo, GetStdHandle, CONSOLE_SCREEN_BUFFER_INFO, STD_OUTPUT_HANDLE,
+    },
+    Win32::System::Diagnostics::ToolHelp::{
+        CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W,
+        TH32CS_SNAPPROCESS,
+    },
+    Win32::System::Ioctl::{
+        NVMeDataTypeLogPage, PropertyStandardQuery, ProtocolTypeNvme, StorageDeviceProperty,
+        StorageDeviceProtocolSpecificProperty, IOCTL_STORAGE_QUERY_PROPERTY,
+        STORAGE_DEVICE_DESCRIPTOR, STORAGE_PROPERTY_ID, STORAGE_PROPERTY_QUERY,
+        STORAGE_PROTOCOL_SPECIFIC_DATA, STORAGE_QUERY_TYPE,
+    },
+    Win32::System::Power::SYSTEM_POWER_CAPABILITIES,
+    Win32::System::Power::{CallNtPowerInformation, SystemPowerCapabilities},
+    Win32::System::ProcessStatus::{K32GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS},
+    Win32::System::RemoteDesktop::{
+        WTSClientProtocolType, WTSEnumerateSessionsW, WTSFreeMemory, WTSQuerySessionInformationW,
+        WTSUserName, WTS_CURRENT_SESSION, WTS_SESSION_INFOW,
+    },
     Win32::System::SystemInformation::GetComputerNameExA,
     Win32::System::SystemInformation::GetTickCount64,
     Win32::System::SystemInformation::GlobalMemoryStatusEx,
     Win32::System::SystemInformation::MEMORYSTATUSEX,
-    Win32::System::WindowsProgramming::GetUserNameA,
+    Win32::System::SystemInformation::{
+        GetLogicalProcessorInformationEx, RelationAll, RelationProcessorCore,
+        RelationProcessorPackage, SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
+    },
+    Win32::System::Threading::{GetProcessTimes, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION},
+    Win32::System::WindowsProgramming::{GetUserNameA, DRIVE_REMOVABLE},
+    Win32::System::IO::DeviceIoControl,
+    Win32::UI::Input::KeyboardAndMouse::GetKeyboardLayoutNameW,
+    Win32::UI::WindowsAndMessaging::{
+        GetSystemMetrics, SystemParametersInfoW, NID_EXTERNAL_TOUCH, NID_INTEGRATED_TOUCH,
+        SM_DIGITIZER, SM_REMOTESESSION, SPI_GETMOUSESPEED, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS,
+    },
 };
 
+#[cfg(feature = "full")]
+use windows::{
+    Win32::Devices::FunctionDiscovery::PKEY_Device_FriendlyName,
+    Win32::Foundation::{
+        BSTR, ERROR_BUFFER_OVERFLOW, ERROR_INSUFFICIENT_BUFFER, NO_ERROR, RPC_E_CALL_REJECTED,
+        RPC_E_SERVERCALL_RETRYLATER, RPC_E_TIMEOUT, WIN32_ERROR,
+    },
+    Win32::Media::Audio::{eConsole, eRender, IMMDeviceEnumerator, MMDeviceEnumerator},
+    Win32::NetworkManagement::IpHelper::{
+        FreeMibTable, GetAdaptersAddresses, GetExtendedTcpTable, GetIfTable2,
+        GAA_FLAG_SKIP_ANYCAST, GAA_FLAG_SKIP_DNS_SERVER, GAA_FLAG_SKIP_MULTICAST,
+        IP_ADAPTER_ADDRESSES_LH, MIB_IF_ROW2, MIB_IF_TABLE2, MIB_TCP6TABLE_OWNER_PID,
+        MIB_TCPTABLE_OWNER_PID, MIB_TCP_STATE_ESTAB, TCP_TABLE_OWNER_PID_ALL,
+    },
+    Win32::NetworkManagement::Ndis::IfOperStatusUp,
+    Win32::Networking::WinSock::{
+        ADDRESS_FAMILY, AF_INET, AF_INET6, AF_UNSPEC, SOCKADDR_IN, SOCKADDR_IN6,
+    },
+    Win32::System::Com::StructuredStorage::{PropVariantClear, STGM_READ},
+    Win32::System::Com::{CoCreateInstance, CLSCTX_ALL},
+    Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS},
+    Win32::System::UpdateAgent::{IUpdateSession, UpdateSession},
+};
+
+#[cfg(feature = "full")]
 impl From<wmi::WMIError> for ReadoutError {
     fn from(e: wmi::WMIError) -> Self {
-        ReadoutError::Other(e.to_string())
+        // WMI runs out of process, its calls can time out or be turned down while the service
+        // is busy or restarting.
+        const WBEM_E_TIMED_OUT: i32 = 0x80043001u32 as i32;
+        const WBEM_E_SHUTTING_DOWN: i32 = 0x80041033u32 as i32;
+
+        match e {
+            wmi::WMIError::HResultError { hres }
+                if [
+                    WBEM_E_TIMED_OUT,
+                    WBEM_E_SHUTTING_DOWN,
+                    RPC_E_CALL_REJECTED.0,
+                    RPC_E_SERVERCALL_RETRYLATER.0,
+                    RPC_E_TIMEOUT.0,
+                ]
+                .contains(&hres) =>
+            {
+                ReadoutError::Transient(e.to_string())
+            }
+            _ => ReadoutError::Other(e.to_string()),
+        }
     }
 }
 
+#[cfg(feature = "full")]
 pub struct WindowsBatteryReadout;
 
+#[cfg(feature = "full")]
 impl BatteryReadout for WindowsBatteryReadout {
     fn new() -> Self {
         WindowsBatteryReadout {}
@@ -41,11 +144,22 @@ impl BatteryReadout for WindowsBatteryReadout {
         }
     }
 
+    /// This falls back to the value of `BatteryLifePercent`, which some firmwares round to
+    /// steps of 10%.
+    fn percentage_precise(&self) -> Result<f32, ReadoutError> {
+        self.percentage().map(f32::from)
+    }
+
     fn status(&self) -> Result<BatteryState, ReadoutError> {
         let power_state = WindowsBatteryReadout::get_power_status()?;
 
+        // A battery that's plugged in, but isn't flagged as charging (8), is full once it
+        // reaches 100%. Some firmwares don't set the flags at all, reporting 255 instead.
+        let is_charging = power_state.BatteryFlag != 255 && power_state.BatteryFlag & 8 != 0;
+
         match power_state.ACLineStatus {
             0 => Ok(BatteryState::Discharging),
+            1 if power_state.BatteryLifePercent == 100 && !is_charging => Ok(BatteryState::Full),
             1 => Ok(BatteryState::Charging),
             a => Err(ReadoutError::Other(format!(
                 "Unexpected value for ac_line_status from win32 api: {a}"
@@ -56,8 +170,36 @@ impl BatteryReadout for WindowsBatteryReadout {
     fn health(&self) -> Result<u8, ReadoutError> {
         Err(ReadoutError::NotImplemented)
     }
+
+    fn is_present(&self) -> bool {
+        // A battery flag of 128 means "no system battery", and a percentage of 255 means
+        // the percentage is unknown, which is also what desktops report.
+        match WindowsBatteryReadout::get_power_status() {
+            Ok(power_state) => {
+                power_state.BatteryFlag != 128 && power_state.BatteryLifePercent != 255
+            }
+            Err(_) => false,
+        }
+    }
+
+    fn manufacturer(&self) -> Result<String, ReadoutError> {
+        WindowsBatteryReadout::battery_static_data("ManufactureName")
+    }
+
+    fn model(&self) -> Result<String, ReadoutError> {
+        WindowsBatteryReadout::battery_static_data("DeviceName")
+    }
+
+    fn energy_now(&self) -> Result<u64, ReadoutError> {
+        WindowsBatteryReadout::battery_capacity("RemainingCapacity", "BatteryStatus")
+    }
+
+    fn energy_full(&self) -> Result<u64, ReadoutError> {
+        WindowsBatteryReadout::battery_capacity("FullChargedCapacity", "BatteryFullChargedCapacity")
+    }
 }
 
+#[cfg(feature = "full")]
 impl WindowsBatteryReadout {
     fn get_power_status() -> Result<SYSTEM_POWER_STATUS, ReadoutError> {
         let mut power_state = SYSTEM_POWER_STATUS::default();
@@ -70,6 +212,36 @@ impl WindowsBatteryReadout {
             "Call to GetSystemPowerStatus failed.",
         )))
     }
+
+    /// Reads a capacity of the first battery from the given class of the `root\WMI`
+    /// namespace, converting it from milliwatt-hours to microwatt-hours.
+    fn battery_capacity(property: &str, class: &str) -> Result<u64, ReadoutError> {
+        let wmi_con = wmi_connection_with_namespace("root\\WMI")?;
+
+        let results: Vec<HashMap<String, Variant>> =
+            wmi_con.raw_query(format!("SELECT {property} FROM {class}"))?;
+
+        match results.first().and_then(|battery| battery.get(property)) {
+            Some(Variant::UI4(capacity)) => Ok(*capacity as u64 * 1000),
+            _ => Err(ReadoutError::MetricNotAvailable),
+        }
+    }
+
+    /// Reads a property of the first battery's `BatteryStaticData` instance, which the
+    /// battery's driver publishes in the `root\WMI` namespace.
+    fn battery_static_data(property: &str) -> Result<String, ReadoutError> {
+        let wmi_con = wmi_connection_with_namespace("root\\WMI")?;
+
+        let results: Vec<HashMap<String, Variant>> =
+            wmi_con.raw_query(format!("SELECT {property} FROM BatteryStaticData"))?;
+
+        match results.first().and_then(|battery| battery.get(property)) {
+            Some(Variant::String(value)) if !value.trim().is_empty() => {
+                Ok(value.trim().to_string())
+            }
+            _ => Err(ReadoutError::MetricNotAvailable),
+        }
+    }
 }
 
 pub struct WindowsKernelReadout;
@@ -79,6 +251,7 @@ impl KernelReadout for WindowsKernelReadout {
         WindowsKernelReadout {}
     }
 
+    #[cfg(feature = "full")]
     fn os_release(&self) -> Result<String, ReadoutError> {
         let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
         let current_windows_not =
@@ -89,8 +262,47 @@ impl KernelReadout for WindowsKernelReadout {
         Ok(nt_build)
     }
 
+    #[cfg(not(feature = "full"))]
+    fn os_release(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    #[cfg(feature = "full")]
+    fn os_type(&self) -> Result<String, ReadoutError> {
+        let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+        let current_version =
+            hklm.open_subkey("SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion")?;
+
+        // The product name includes the edition, e.g. "Windows 10 Pro", whereas EditionID
+        // only holds the edition itself, e.g. "Professional".
+        let product_name = match current_version.get_value::<String, _>("ProductName") {
+            Ok(product_name) => product_name,
+            Err(_) => match current_version.get_value::<String, _>("EditionID") {
+                Ok(edition) => format!("Windows {edition}"),
+                Err(_) => return Ok(String::from("Windows NT")),
+            },
+        };
+
+        // Windows 11 still identifies itself as Windows 10 in the registry, it can only be
+        // told apart by its build number, starting from 22000.
+        let build: u32 = current_version
+            .get_value::<String, _>("CurrentBuild")
+            .ok()
+            .and_then(|build| build.parse().ok())
+            .unwrap_or_default();
+
+        if build >= 22000 {
+            if let Some(edition) = product_name.strip_prefix("Windows 10") {
+                return Ok(format!("Windows 11{edition}"));
+            }
+        }
+
+        Ok(product_name)
+    }
+
+    #[cfg(not(feature = "full"))]
     fn os_type(&self) -> Result<String, ReadoutError> {
-        Ok(String::from("Windows NT"))
+        Err(ReadoutError::NotImplemented)
     }
 
     fn pretty_kernel(&self) -> Result<String, ReadoutError> {
@@ -130,6 +342,40 @@ impl MemoryReadout for WindowsMemoryReadout {
         let memory_status = WindowsMemoryReadout::get_memory_status()?;
         Ok((memory_status.ullTotalPhys - memory_status.ullAvailPhys) / 1024u64)
     }
+
+    #[cfg(feature = "full")]
+    fn swap_total(&self) -> Result<u64, ReadoutError> {
+        let (total, _) = WindowsMemoryReadout::page_file_usage()?;
+        Ok(total)
+    }
+
+    #[cfg(feature = "full")]
+    fn swap_used(&self) -> Result<u64, ReadoutError> {
+        let (_, used) = WindowsMemoryReadout::page_file_usage()?;
+        Ok(used)
+    }
+
+    #[cfg(feature = "full")]
+    fn swap_io(&self) -> Result<(u64, u64), ReadoutError> {
+        // The raw values behind the "Pages Input/sec" and "Pages Output/sec" counters are
+        // the number of pages read from and written to disk since boot.
+        let results: Vec<HashMap<String, Variant>> = wmi_connection()?.raw_query(
+            "SELECT PagesInputPersec, PagesOutputPersec FROM Win32_PerfRawData_PerfOS_Memory",
+        )?;
+
+        let memory = results.first().ok_or(ReadoutError::MetricNotAvailable)?;
+        let pages = |property: &str| match memory.get(property) {
+            Some(Variant::UI4(pages)) => Some(*pages as u64),
+            Some(Variant::UI8(pages)) => Some(*pages),
+            Some(Variant::String(pages)) => pages.parse::<u64>().ok(),
+            _ => None,
+        };
+
+        match (pages("PagesInputPersec"), pages("PagesOutputPersec")) {
+            (Some(swapped_in), Some(swapped_out)) => Ok((swapped_in, swapped_out)),
+            _ => Err(ReadoutError::MetricNotAvailable),
+        }
+    }
 }
 
 impl WindowsMemoryReadout {
@@ -147,17 +393,100 @@ impl WindowsMemoryReadout {
 
         Ok(memory_status)
     }
+
+    /// Returns the allocated and used size of all page files in kilobytes.
+    #[cfg(feature = "full")]
+    fn page_file_usage() -> Result<(u64, u64), ReadoutError> {
+        let wmi_con = wmi_connection()?;
+
+        let results: Vec<HashMap<String, Variant>> =
+            wmi_con.raw_query("SELECT AllocatedBaseSize, CurrentUsage FROM Win32_PageFileUsage")?;
+
+        if results.is_empty() {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        // Both properties are reported in megabytes.
+        let (mut total, mut used) = (0u64, 0u64);
+        for page_file in &results {
+            if let Some(Variant::UI4(size)) = page_file.get("AllocatedBaseSize") {
+                total += *size as u64 * 1024;
+            }
+
+            if let Some(Variant::UI4(usage)) = page_file.get("CurrentUsage") {
+                used += *usage as u64 * 1024;
+            }
+        }
+
+        Ok((total, used))
+    }
 }
 
+#[cfg(feature = "full")]
 thread_local! {
     static COM_LIB: COMLibrary = COMLibrary::new().unwrap();
 }
 
+/// Returns the path to the current user's "Documents" folder, which may have been moved
+/// elsewhere, _e.g._ by OneDrive.
+fn documents_dir() -> Option<PathBuf> {
+    #[cfg(feature = "full")]
+    let personal = RegKey::predef(HKEY_CURRENT_USER)
+        .open_subkey("Software\\Microsoft\\Windows\\CurrentVersion\\Explorer\\Shell Folders")
+        .and_then(|folders| folders.get_value::<String, _>("Personal"))
+        .map(PathBuf::from)
+        .ok();
+    #[cfg(not(feature = "full"))]
+    let personal = None;
+
+    personal.or_else(|| home::home_dir().map(|home| home.join("Documents")))
+}
+
+#[cfg(feature = "full")]
 fn wmi_connection() -> WMIResult<WMIConnection> {
     let com_lib = COM_LIB.with(|com| *com);
     WMIConnection::new(com_lib)
 }
 
+#[cfg(feature = "full")]
+fn wmi_connection_with_namespace(namespace_path: &str) -> WMIResult<WMIConnection> {
+    let com_lib = COM_LIB.with(|com| *com);
+    WMIConnection::with_namespace_path(namespace_path, com_lib)
+}
+
+#[cfg(feature = "full")]
+/// A connection to the Windows Management Instrumentation service, which can be used to query
+/// the classes libmacchina doesn't provide a readout for, _e.g._ `Win32_Fan`.
+///
+/// The connection is established once and reused for every query made through it.
+pub struct WindowsWmi {
+    connection: WMIConnection,
+}
+
+#[cfg(feature = "full")]
+impl WindowsWmi {
+    /// Connects to the default `root\cimv2` namespace.
+    pub fn new() -> Result<Self, ReadoutError> {
+        Ok(WindowsWmi {
+            connection: wmi_connection()?,
+        })
+    }
+
+    /// Connects to the given namespace, _e.g._ `root\WMI`.
+    pub fn with_namespace(namespace_path: &str) -> Result<Self, ReadoutError> {
+        Ok(WindowsWmi {
+            connection: wmi_connection_with_namespace(namespace_path)?,
+        })
+    }
+
+    /// Runs the given WQL query, returning the properties of each object it matched.
+    ///
+    /// _e.g._ `SELECT Name, DesiredSpeed FROM Win32_Fan`
+    pub fn query_wmi(&self, query: &str) -> Result<Vec<HashMap<String, Variant>>, ReadoutError> {
+        Ok(self.connection.raw_query(query)?)
+    }
+}
+
 pub struct WindowsGeneralReadout;
 
 impl GeneralReadout for WindowsGeneralReadout {
@@ -209,6 +538,52 @@ impl GeneralReadout for WindowsGeneralReadout {
         Ok(str)
     }
 
+    fn logged_in_user_names(&self) -> Result<Vec<String>, ReadoutError> {
+        // A null handle refers to the local machine.
+        let server = HANDLE(0);
+
+        let mut sessions: *mut WTS_SESSION_INFOW = std::ptr::null_mut();
+        let mut count = 0u32;
+        if !unsafe { WTSEnumerateSessionsW(server, 0, 1, &mut sessions, &mut count) }.as_bool() {
+            return Err(ReadoutError::Other(String::from(
+                "Call to WTSEnumerateSessionsW failed.",
+            )));
+        }
+
+        let mut users: Vec<String> = vec![];
+        for session in unsafe { std::slice::from_raw_parts(sessions, count as usize) } {
+            let mut buffer = PWSTR::null();
+            let mut bytes = 0u32;
+            let queried = unsafe {
+                WTSQuerySessionInformationW(
+                    server,
+                    session.SessionId,
+                    WTSUserName,
+                    &mut buffer,
+                    &mut bytes,
+                )
+            };
+
+            if queried.as_bool() && !buffer.is_null() {
+                // Sessions nobody is logged into, such as session 0, have an empty user name.
+                if let Ok(user) = unsafe { buffer.to_string() } {
+                    if !user.is_empty() {
+                        users.push(user);
+                    }
+                }
+
+                unsafe { WTSFreeMemory(buffer.as_ptr() as *mut _) };
+            }
+        }
+
+        unsafe { WTSFreeMemory(sessions as *mut _) };
+
+        users.sort();
+        users.dedup();
+
+        Ok(users)
+    }
+
     fn hostname(&self) -> Result<String, ReadoutError> {
         use windows::Win32::System::SystemInformation::ComputerNameDnsHostname;
 
@@ -265,7 +640,42 @@ impl GeneralReadout for WindowsGeneralReadout {
     }
 
     fn session(&self) -> Result<String, ReadoutError> {
-        Err(ReadoutError::NotImplemented)
+        // WSLg runs a Wayland compositor, and the Linux GUI applications it hosts show up as
+        // regular Windows processes to some tools.
+        if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            return Ok(String::from("Wayland"));
+        }
+
+        // The protocol the client of the current session connects through, where 0 stands
+        // for the console and 2 for the Remote Desktop Protocol.
+        let mut buffer = PWSTR::null();
+        let mut bytes = 0u32;
+        let queried = unsafe {
+            WTSQuerySessionInformationW(
+                HANDLE(0),
+                WTS_CURRENT_SESSION,
+                WTSClientProtocolType,
+                &mut buffer,
+                &mut bytes,
+            )
+        };
+
+        let mut protocol = None;
+        if queried.as_bool() && !buffer.is_null() {
+            if bytes as usize >= std::mem::size_of::<u16>() {
+                protocol = Some(unsafe { *(buffer.as_ptr() as *const u16) });
+            }
+
+            unsafe { WTSFreeMemory(buffer.as_ptr() as *mut _) };
+        }
+
+        match protocol {
+            Some(0) => Ok(String::from("Console")),
+            Some(2) => Ok(String::from("RDP")),
+            // Otherwise, rely on whether the session is remotely controlled at all.
+            _ if unsafe { GetSystemMetrics(SM_REMOTESESSION) } != 0 => Ok(String::from("RDP")),
+            _ => Ok(String::from("Console")),
+        }
     }
 
     fn window_manager(&self) -> Result<String, ReadoutError> {
@@ -276,10 +686,107 @@ impl GeneralReadout for WindowsGeneralReadout {
         Err(ReadoutError::NotImplemented)
     }
 
+    fn system_language(&self) -> Result<String, ReadoutError> {
+        let language = unsafe { GetUserDefaultUILanguage() };
+
+        // LOCALE_NAME_MAX_LENGTH, which isn't exposed by the windows crate.
+        let mut name = [0u16; 85];
+        let len = unsafe { LCIDToLocaleName(language as u32, &mut name, 0) };
+        if len <= 1 {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        // The length includes the terminating null character.
+        Ok(String::from_utf16_lossy(&name[..len as usize - 1]))
+    }
+
+    fn hibernation_supported(&self) -> Result<bool, ReadoutError> {
+        let mut capabilities = SYSTEM_POWER_CAPABILITIES::default();
+        let status = unsafe {
+            CallNtPowerInformation(
+                SystemPowerCapabilities,
+                std::ptr::null(),
+                0,
+                &mut capabilities as *mut _ as *mut std::ffi::c_void,
+                std::mem::size_of::<SYSTEM_POWER_CAPABILITIES>() as u32,
+            )
+        };
+
+        if status != 0 {
+            return Err(ReadoutError::Other(String::from(
+                "Call to CallNtPowerInformation failed.",
+            )));
+        }
+
+        Ok(capabilities.SystemS4.0 != 0)
+    }
+
+    #[cfg(feature = "full")]
+    fn thunderbolt_security_level(&self) -> Result<String, ReadoutError> {
+        // Windows has no API for it, but the Thunderbolt service keeps the level set in the
+        // firmware in its parameters, either by name or by number.
+        let parameters = RegKey::predef(HKEY_LOCAL_MACHINE)
+            .open_subkey("SYSTEM\\CurrentControlSet\\Services\\thunderbolt\\parameters")
+            .map_err(|_| ReadoutError::MetricNotAvailable)?;
+
+        if let Ok(level) = parameters.get_value::<String, _>("security_level") {
+            return Ok(level.to_lowercase());
+        }
+
+        let level = match parameters.get_value::<u32, _>("security_level") {
+            Ok(0) => "none",
+            Ok(1) => "user",
+            Ok(2) => "secure",
+            Ok(3) => "dponly",
+            _ => return Err(ReadoutError::MetricNotAvailable),
+        };
+
+        Ok(String::from(level))
+    }
+
+    fn terminal_size(&self) -> Result<(u16, u16), ReadoutError> {
+        let mut info = CONSOLE_SCREEN_BUFFER_INFO::default();
+
+        // Getting the buffer's info fails when the standard output isn't a console.
+        let is_console = match unsafe { GetStdHandle(STD_OUTPUT_HANDLE) } {
+            Ok(handle) => unsafe { GetConsoleScreenBufferInfo(handle, &mut info) }.as_bool(),
+            Err(_) => false,
+        };
+
+        if !is_console {
+            return Err(ReadoutError::Warning(String::from(
+                "The standard output is not a console.",
+            )));
+        }
+
+        // The buffer may be larger than what's visible of it, which is its window.
+        let window = info.srWindow;
+        Ok((
+            (window.Right - window.Left + 1) as u16,
+            (window.Bottom - window.Top + 1) as u16,
+        ))
+    }
+
     fn shell(&self, _shorthand: ShellFormat, _: ShellKind) -> Result<String, ReadoutError> {
         Err(ReadoutError::NotImplemented)
     }
 
+    fn shell_config_path(&self) -> Result<PathBuf, ReadoutError> {
+        let documents = documents_dir().ok_or(ReadoutError::MetricNotAvailable)?;
+
+        // PowerShell 7 and later keep their profile apart from the one of Windows PowerShell,
+        // which ships with the operating system.
+        let powershell = documents.join("PowerShell");
+        let profile_dir = if powershell.is_dir() {
+            powershell
+        } else {
+            documents.join("WindowsPowerShell")
+        };
+
+        Ok(profile_dir.join("Microsoft.PowerShell_profile.ps1"))
+    }
+
+    #[cfg(feature = "full")]
     fn cpu_model_name(&self) -> Result<String, ReadoutError> {
         let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
         let central_processor =
@@ -290,23 +797,141 @@ impl GeneralReadout for WindowsGeneralReadout {
         Ok(processor_name)
     }
 
+    #[cfg(not(feature = "full"))]
+    fn cpu_model_name(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
     fn cpu_usage(&self) -> Result<usize, ReadoutError> {
         Err(ReadoutError::NotImplemented)
     }
 
     fn cpu_physical_cores(&self) -> Result<usize, ReadoutError> {
-        Err(ReadoutError::NotImplemented)
+        let topology = self.cpu_topology()?;
+        Ok(topology.sockets * topology.cores_per_socket)
     }
 
     fn cpu_cores(&self) -> Result<usize, ReadoutError> {
-        Err(ReadoutError::NotImplemented)
+        Ok(self.cpu_topology()?.total_threads)
+    }
+
+    fn cpu_topology(&self) -> Result<CpuTopology, ReadoutError> {
+        // The first call fails, telling us how much space the records need.
+        let mut size: u32 = 0;
+        unsafe { GetLogicalProcessorInformationEx(RelationAll, std::ptr::null_mut(), &mut size) };
+
+        let mut buffer: Vec<u64> = vec![0; size as usize / std::mem::size_of::<u64>() + 1];
+        let ok = unsafe {
+            GetLogicalProcessorInformationEx(
+                RelationAll,
+                buffer.as_mut_ptr() as *mut SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
+                &mut size,
+            )
+        };
+
+        if !ok.as_bool() {
+            return Err(ReadoutError::Other(String::from(
+                "Failed to retrieve the topology of the processors.",
+            )));
+        }
+
+        let (mut sockets, mut cores) = (0, 0);
+        let (mut total_threads, mut threads_per_core) = (0, 0);
+
+        // The records vary in size, each of them giving the offset of the next one.
+        let mut offset = 0;
+        while offset < size as usize {
+            let info = unsafe { (buffer.as_ptr() as *const u8).add(offset) }
+                as *const SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX;
+            let (relationship, len) = unsafe { ((*info).Relationship, (*info).Size) };
+
+            if relationship == RelationProcessorPackage {
+                sockets += 1;
+            } else if relationship == RelationProcessorCore {
+                // A core's logical processors all belong to the same group.
+                let mask = unsafe { (*info).Anonymous.Processor.GroupMask[0].Mask };
+                let threads = mask.count_ones() as usize;
+
+                cores += 1;
+                total_threads += threads;
+                threads_per_core = threads_per_core.max(threads);
+            }
+
+            if len == 0 {
+                break;
+            }
+
+            offset += len as usize;
+        }
+
+        if sockets == 0 || cores == 0 {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        Ok(CpuTopology {
+            sockets,
+            cores_per_socket: cores / sockets,
+            threads_per_core,
+            total_threads,
+        })
+    }
+
+    #[cfg(feature = "full")]
+    fn cpu_hyper_threading(&self) -> Result<bool, ReadoutError> {
+        let results: Vec<HashMap<String, Variant>> = wmi_connection()?
+            .raw_query("SELECT NumberOfCores, NumberOfLogicalProcessors FROM Win32_Processor")?;
+
+        if results.is_empty() {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        // Machines with several sockets report one instance per processor.
+        let (mut cores, mut logical_processors) = (0, 0);
+        for cpu in &results {
+            match (
+                cpu.get("NumberOfCores"),
+                cpu.get("NumberOfLogicalProcessors"),
+            ) {
+                (Some(Variant::UI4(c)), Some(Variant::UI4(l))) => {
+                    cores += c;
+                    logical_processors += l;
+                }
+                _ => return Err(ReadoutError::MetricNotAvailable),
+            }
+        }
+
+        Ok(logical_processors > cores)
+    }
+
+    #[cfg(feature = "full")]
+    fn cpu_max_frequency(&self) -> Result<u64, ReadoutError> {
+        let results: Vec<HashMap<String, Variant>> =
+            wmi_connection()?.raw_query("SELECT MaxClockSpeed FROM Win32_Processor")?;
+
+        match results.first().and_then(|cpu| cpu.get("MaxClockSpeed")) {
+            Some(Variant::UI4(mhz)) => Ok(*mhz as u64),
+            _ => Err(ReadoutError::MetricNotAvailable),
+        }
     }
 
     fn uptime(&self) -> Result<usize, ReadoutError> {
         let tick_count = unsafe { GetTickCount64() };
-        let duration = std::time::Duration::from_millis(tick_count);
+        let uptime = std::time::Duration::from_millis(tick_count).as_secs();
+
+        // The tick count may jump on virtual machines, e.g. after a live migration, in which
+        // case we trust the boot time recorded by the operating system instead.
+        #[cfg(feature = "full")]
+        {
+            const MAX_DRIFT: u64 = 60;
+
+            if let Ok(wmi_uptime) = WindowsGeneralReadout::uptime_since_last_boot() {
+                if wmi_uptime.abs_diff(uptime) > MAX_DRIFT {
+                    return Ok(wmi_uptime as usize);
+                }
+            }
+        }
 
-        Ok(duration.as_secs() as usize)
+        Ok(uptime as usize)
     }
 
     fn machine(&self) -> Result<String, ReadoutError> {
@@ -319,6 +944,7 @@ impl GeneralReadout for WindowsGeneralReadout {
         ))
     }
 
+    #[cfg(feature = "full")]
     fn os_name(&self) -> Result<String, ReadoutError> {
         let wmi_con = wmi_connection()?;
 
@@ -338,100 +964,1109 @@ impl GeneralReadout for WindowsGeneralReadout {
         ))
     }
 
+    #[cfg(not(feature = "full"))]
+    fn os_name(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
     fn disk_space(&self) -> Result<(u64, u64), ReadoutError> {
         Err(ReadoutError::NotImplemented)
     }
 
+    #[cfg(all(feature = "nvml", feature = "full"))]
+    fn gpus(&self) -> Result<Vec<String>, ReadoutError> {
+        // Only the GPUs managed by NVIDIA's driver are listed.
+        let nvml = crate::nvml::Nvml::load().ok_or(ReadoutError::NotImplemented)?;
+        let gpus: Vec<String> = nvml.gpus().into_iter().map(|gpu| gpu.name).collect();
+
+        if gpus.is_empty() {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        Ok(gpus)
+    }
+
+    #[cfg(not(all(feature = "nvml", feature = "full")))]
     fn gpus(&self) -> Result<Vec<String>, ReadoutError> {
         Err(ReadoutError::NotImplemented)
     }
-}
 
-pub struct WindowsProductReadout {
-    manufacturer: Option<String>,
-    model: Option<String>,
-}
+    #[cfg(feature = "full")]
+    fn gpu_count(&self) -> Result<usize, ReadoutError> {
+        let wmi_con = wmi_connection()?;
 
-impl ProductReadout for WindowsProductReadout {
-    fn new() -> Self {
-        let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
-        let sys_info = hklm
-            .open_subkey("SYSTEM\\CurrentControlSet\\Control\\SystemInformation")
-            .unwrap();
+        let results: Vec<HashMap<String, Variant>> =
+            wmi_con.raw_query("SELECT DeviceID FROM Win32_VideoController")?;
 
-        WindowsProductReadout {
-            manufacturer: sys_info.get_value("SystemManufacturer").ok(),
-            model: sys_info.get_value("SystemProductName").ok(),
-        }
+        Ok(results.len())
     }
 
-    fn vendor(&self) -> Result<String, ReadoutError> {
-        match &self.manufacturer {
-            Some(v) => Ok(v.clone()),
-            None => Err(ReadoutError::Other(
-                "Trying to get the system manufacturer \
-                from the registry failed"
-                    .to_string(),
-            )),
+    #[cfg(feature = "full")]
+    fn gpu_temp(&self) -> Result<Vec<(String, f32)>, ReadoutError> {
+        #[cfg(feature = "nvml")]
+        {
+            let mut temperatures =
+                WindowsGeneralReadout::librehardwaremonitor_gpu_temp().unwrap_or_default();
+            crate::nvml::Nvml::merge_temperatures(&mut temperatures);
+
+            if !temperatures.is_empty() {
+                return Ok(temperatures);
+            }
         }
-    }
 
-    fn family(&self) -> Result<String, ReadoutError> {
-        Err(ReadoutError::NotImplemented)
+        WindowsGeneralReadout::librehardwaremonitor_gpu_temp()
     }
 
-    fn product(&self) -> Result<String, ReadoutError> {
-        match &self.model {
-            Some(v) => Ok(v.clone()),
-            None => Err(ReadoutError::Other(
-                "Trying to get the system product name \
-                from the registry failed"
-                    .to_string(),
-            )),
+    fn gpu_vram(&self) -> Result<Vec<(String, u64)>, ReadoutError> {
+        let factory: IDXGIFactory1 = unsafe { CreateDXGIFactory1() }
+            .map_err(|e| ReadoutError::Other(e.message().to_string()))?;
+
+        let mut vram = vec![];
+        for adapter in (0..).map_while(|i| unsafe { factory.EnumAdapters1(i) }.ok()) {
+            let Ok(description) = (unsafe { adapter.GetDesc1() }) else {
+                continue;
+            };
+
+            // Leave out the Microsoft Basic Render Driver.
+            if description.Flags & DXGI_ADAPTER_FLAG_SOFTWARE.0 != 0 {
+                continue;
+            }
+
+            let len = description
+                .Description
+                .iter()
+                .position(|&c| c == 0)
+                .unwrap_or(description.Description.len());
+            let name = String::from_utf16_lossy(&description.Description[..len]);
+
+            // Unlike Win32_VideoController's AdapterRAM, this doesn't wrap around past 4 GB.
+            let megabytes = description.DedicatedVideoMemory as u64 / 1024 / 1024;
+            vram.push((name, megabytes));
+        }
+
+        if vram.is_empty() {
+            Err(ReadoutError::MetricNotAvailable)
+        } else {
+            Ok(vram)
         }
     }
-}
 
-pub struct WindowsPackageReadout;
+    #[cfg(feature = "full")]
+    fn cpu_temperature(&self) -> Result<f32, ReadoutError> {
+        let wmi_con = wmi_connection_with_namespace("root\\WMI")?;
 
-impl PackageReadout for WindowsPackageReadout {
-    fn new() -> Self {
-        WindowsPackageReadout {}
+        let results: Vec<HashMap<String, Variant>> =
+            wmi_con.raw_query("SELECT CurrentTemperature FROM MSAcpi_ThermalZoneTemperature")?;
+
+        // The temperature of each thermal zone is reported in tenths of a Kelvin, the
+        // hottest one is the closest we get to the CPU's temperature.
+        results
+            .iter()
+            .filter_map(|zone| match zone.get("CurrentTemperature") {
+                Some(Variant::UI4(temperature)) => Some(*temperature as f32 / 10.0 - 273.15),
+                _ => None,
+            })
+            .reduce(f32::max)
+            .ok_or(ReadoutError::MetricNotAvailable)
     }
 
-    /// Returns the __number of installed packages__ for the following package managers:
-    /// - cargo
+    fn keyboard_layout(&self) -> Result<String, ReadoutError> {
+        let mut klid = [0u16; 9];
+        if !unsafe { GetKeyboardLayoutNameW(&mut klid) }.as_bool() {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        // The identifier of the layout, e.g. "00000409" for the US layout.
+        let klid = String::from_utf16_lossy(&klid[..8]);
+
+        #[cfg(feature = "full")]
+        {
+            let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+            if let Ok(name) = hklm
+                .open_subkey(format!(
+                    "SYSTEM\\CurrentControlSet\\Control\\Keyboard Layouts\\{klid}"
+                ))
+                .and_then(|layout| layout.get_value::<String, _>("Layout Text"))
+            {
+                return Ok(name);
+            }
+        }
+
+        // Otherwise, fall back to the name of the language the layout was made for, which
+        // the lower half of its identifier stands for.
+        let language = klid
+            .get(4..)
+            .and_then(|language| u32::from_str_radix(language, 16).ok())
+            .ok_or(ReadoutError::MetricNotAvailable)?;
+
+        let mut name = [0u16; 128];
+        let len = unsafe { GetLocaleInfoW(language, LOCALE_SLANGUAGE, &mut name) };
+        if len <= 1 {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        // The length includes the terminating null character.
+        Ok(String::from_utf16_lossy(&name[..len as usize - 1]))
+    }
+
+    fn mouse_speed(&self) -> Result<u32, ReadoutError> {
+        let mut speed: u32 = 0;
+        let ok = unsafe {
+            SystemParametersInfoW(
+                SPI_GETMOUSESPEED,
+                0,
+                &mut speed as *mut _ as *mut std::ffi::c_void,
+                SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+            )
+        };
+
+        if !ok.as_bool() {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        Ok(speed)
+    }
+
+    #[cfg(feature = "full")]
+    fn default_audio_output(&self) -> Result<String, ReadoutError> {
+        // The device enumerator is a COM object, which requires COM to be initialized.
+        COM_LIB.with(|_| {});
+
+        let enumerator: IMMDeviceEnumerator =
+            unsafe { CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL) }
+                .map_err(|e| ReadoutError::Other(e.message().to_string()))?;
+
+        let device =
+            unsafe { enumerator.GetDefaultAudioEndpoint(eRender, eConsole) }.map_err(|_| {
+                ReadoutError::Warning(String::from("No audio output device is available."))
+            })?;
+
+        let name = unsafe {
+            let mut friendly_name = device
+                .OpenPropertyStore(STGM_READ)
+                .and_then(|store| store.GetValue(&PKEY_Device_FriendlyName))
+                .map_err(|e| ReadoutError::Other(e.message().to_string()))?;
+
+            let name = friendly_name
+                .Anonymous
+                .Anonymous
+                .Anonymous
+                .pwszVal
+                .to_string();
+            let _ = PropVariantClear(&mut friendly_name);
+            name
+        };
+
+        name.map_err(|e| ReadoutError::Other(e.to_string()))
+    }
+
+    #[cfg(feature = "full")]
+    fn pending_updates(&self) -> Result<usize, ReadoutError> {
+        // The Windows Update Agent is exposed through COM, which needs to be initialized.
+        COM_LIB.with(|_| {});
+
+        let count = unsafe {
+            CoCreateInstance::<_, IUpdateSession>(&UpdateSession, None, CLSCTX_ALL)
+                .and_then(|session| session.CreateUpdateSearcher())
+                .and_then(|searcher| {
+                    searcher.Search(&BSTR::from("IsInstalled=0 AND Type='Software'"))
+                })
+                .and_then(|result| result.Updates())
+                .and_then(|updates| updates.Count())
+        }
+        .map_err(|e| ReadoutError::Other(e.message().to_string()))?;
+
+        Ok(count as usize)
+    }
+
+    fn dotnet_versions(&self) -> Result<Vec<String>, ReadoutError> {
+        let mut versions = vec![];
+
+        #[cfg(feature = "full")]
+        {
+            // Every .NET Framework release has a subkey, e.g. "v3.5" or "v4", which holds its
+            // exact version, either directly or in its "Full" and "Client" profiles.
+            let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+            if let Ok(ndp) = hklm.open_subkey("SOFTWARE\\Microsoft\\NET Framework Setup\\NDP") {
+                for name in ndp.enum_keys().filter_map(Result::ok) {
+                    if !name.starts_with('v') {
+                        continue;
+                    }
+
+                    let Ok(release) = ndp.open_subkey(&name) else {
+                        continue;
+                    };
+
+                    let version: Option<String> = release.get_value("Version").ok().or_else(|| {
+                        ["Full", "Client"].iter().find_map(|profile| {
+                            release.open_subkey(profile).ok()?.get_value("Version").ok()
+                        })
+                    });
+
+                    if let Some(version) = version {
+                        versions.push(format!(".NET Framework {version}"));
+                    }
+                }
+            }
+        }
+
+        // .NET Core and .NET 5+ install each of their shared runtimes, e.g.
+        // "Microsoft.NETCore.App", into a directory per version.
+        if let Ok(program_files) = std::env::var("ProgramFiles") {
+            let shared = PathBuf::from(program_files).join("dotnet").join("shared");
+            for runtime in std::fs::read_dir(shared).into_iter().flatten().flatten() {
+                let runtime_name = runtime.file_name().to_string_lossy().into_owned();
+
+                for version in std::fs::read_dir(runtime.path())
+                    .into_iter()
+                    .flatten()
+                    .flatten()
+                {
+                    if version.path().is_dir() {
+                        versions.push(format!(
+                            "{runtime_name} {}",
+                            version.file_name().to_string_lossy()
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(versions)
+    }
+
+    #[cfg(feature = "full")]
+    fn disk_io(&self) -> Result<(u64, u64), ReadoutError> {
+        // The raw values of these counters are the number of bytes transferred since boot,
+        // which WMI hands out as strings given that they're 64-bit integers.
+        let results: Vec<HashMap<String, Variant>> = wmi_connection()?.raw_query(
+            "SELECT DiskReadBytesPersec, DiskWriteBytesPersec \
+             FROM Win32_PerfRawData_PerfDisk_PhysicalDisk WHERE Name = '_Total'",
+        )?;
+
+        let total = results.first().ok_or(ReadoutError::MetricNotAvailable)?;
+        let bytes = |property: &str| match total.get(property) {
+            Some(Variant::String(bytes)) => bytes.parse::<u64>().ok(),
+            Some(Variant::UI8(bytes)) => Some(*bytes),
+            _ => None,
+        };
+
+        match (bytes("DiskReadBytesPersec"), bytes("DiskWriteBytesPersec")) {
+            (Some(read), Some(written)) => Ok((read, written)),
+            _ => Err(ReadoutError::MetricNotAvailable),
+        }
+    }
+
+    fn removable_drives(&self) -> Result<Vec<DriveInfo>, ReadoutError> {
+        let letters = unsafe { GetLogicalDrives() };
+        if letters == 0 {
+            return Err(ReadoutError::Other(String::from(
+                "Failed to enumerate the logical drives.",
+            )));
+        }
+
+        // Each bit of the mask stands for a drive letter, starting with A.
+        let drives = (b'A'..=b'Z')
+            .enumerate()
+            .filter(|(bit, _)| letters & (1 << bit) != 0)
+            .filter_map(|(_, letter)| {
+                let root = format!("{}:\\", letter as char);
+                let wide: Vec<u16> = root.encode_utf16().chain(std::iter::once(0)).collect();
+
+                if unsafe { GetDriveTypeW(PCWSTR(wide.as_ptr())) } != DRIVE_REMOVABLE {
+                    return None;
+                }
+
+                WindowsGeneralReadout::drive_info(root, &wide)
+            })
+            .collect();
+
+        Ok(drives)
+    }
+
+    #[cfg(feature = "full")]
+    fn monitor_names(&self) -> Result<Vec<String>, ReadoutError> {
+        let wmi_con = wmi_connection_with_namespace("root\\WMI")?;
+        let results: Vec<HashMap<String, Variant>> =
+            wmi_con.raw_query("SELECT UserFriendlyName FROM WmiMonitorID WHERE Active = TRUE")?;
+
+        // The monitor name from the EDID is handed out as an array of UTF-16 code units,
+        // padded with zeros.
+        let names = results
+            .iter()
+            .filter_map(|monitor| match monitor.get("UserFriendlyName") {
+                Some(Variant::Array(units)) => Some(
+                    units
+                        .iter()
+                        .filter_map(|unit| match unit {
+                            Variant::UI2(unit) if *unit != 0 => Some(*unit),
+                            _ => None,
+                        })
+                        .collect::<Vec<u16>>(),
+                ),
+                _ => None,
+            })
+            .map(|units| String::from_utf16_lossy(&units).trim().to_string())
+            .filter(|name| !name.is_empty())
+            .collect();
+
+        Ok(names)
+    }
+
+    #[cfg(feature = "full")]
+    fn battery_charging_speed(&self) -> Result<f32, ReadoutError> {
+        let wmi_con = wmi_connection_with_namespace("root\\WMI")?;
+        let results: Vec<HashMap<String, Variant>> =
+            wmi_con.raw_query("SELECT Charging, ChargeRate FROM BatteryStatus")?;
+
+        let battery = results.first().ok_or(ReadoutError::MetricNotAvailable)?;
+        if !matches!(battery.get("Charging"), Some(Variant::Bool(true))) {
+            return Ok(0.0);
+        }
+
+        // The charge rate is reported in milliwatts, and left at zero by the many firmwares
+        // that don't expose it.
+        match battery.get("ChargeRate") {
+            Some(Variant::I4(rate)) if *rate > 0 => Ok(*rate as f32 / 1000.0),
+            _ => Err(ReadoutError::MetricNotAvailable),
+        }
+    }
+
+    fn connected_monitors_info(&self) -> Result<Vec<MonitorInfo>, ReadoutError> {
+        let to_string = |wide: &[u16]| {
+            let len = wide.iter().position(|&c| c == 0).unwrap_or(wide.len());
+            String::from_utf16_lossy(&wide[..len])
+        };
+
+        let mut monitors = vec![];
+
+        // Display devices, e.g. "\\.\DISPLAY1", are enumerated until there's none left.
+        for i in 0.. {
+            let mut device = DISPLAY_DEVICEW {
+                cb: std::mem::size_of::<DISPLAY_DEVICEW>() as u32,
+                ..Default::default()
+            };
+
+            if !unsafe { EnumDisplayDevicesW(PCWSTR::null(), i, &mut device, 0) }.as_bool() {
+                break;
+            }
+
+            if device.StateFlags & DISPLAY_DEVICE_ATTACHED_TO_DESKTOP == 0 {
+                continue;
+            }
+
+            let mut mode = DEVMODEW {
+                dmSize: std::mem::size_of::<DEVMODEW>() as u16,
+                ..Default::default()
+            };
+
+            let device_name = PCWSTR(device.DeviceName.as_ptr());
+            if !unsafe { EnumDisplaySettingsW(device_name, ENUM_CURRENT_SETTINGS, &mut mode) }
+                .as_bool()
+            {
+                continue;
+            }
+
+            // The monitor attached to the display device is enumerated through the latter,
+            // and is named after its driver, e.g. "Generic PnP Monitor".
+            let mut monitor = DISPLAY_DEVICEW {
+                cb: std::mem::size_of::<DISPLAY_DEVICEW>() as u32,
+                ..Default::default()
+            };
+
+            let name = if unsafe { EnumDisplayDevicesW(device_name, 0, &mut monitor, 0) }.as_bool()
+            {
+                to_string(&monitor.DeviceString)
+            } else {
+                to_string(&device.DeviceName)
+            };
+
+            monitors.push(MonitorInfo {
+                name,
+                resolution: (mode.dmPelsWidth, mode.dmPelsHeight),
+                // A frequency of 0 or 1 stands for the hardware's default one.
+                refresh_rate: match mode.dmDisplayFrequency {
+                    0 | 1 => 0,
+                    frequency => frequency,
+                },
+                is_primary: device.StateFlags & DISPLAY_DEVICE_PRIMARY_DEVICE != 0,
+                color_depth: mode.dmBitsPerPel,
+            });
+        }
+
+        Ok(monitors)
+    }
+
+    fn color_depth(&self) -> Result<u8, ReadoutError> {
+        let mut mode = DEVMODEW {
+            dmSize: std::mem::size_of::<DEVMODEW>() as u16,
+            ..Default::default()
+        };
+
+        // A null device name stands for the display the calling thread runs on.
+        if !unsafe { EnumDisplaySettingsW(PCWSTR::null(), ENUM_CURRENT_SETTINGS, &mut mode) }
+            .as_bool()
+        {
+            return Err(ReadoutError::Other(String::from(
+                "Call to \"EnumDisplaySettingsW\" failed.",
+            )));
+        }
+
+        u8::try_from(mode.dmBitsPerPel).map_err(|_| ReadoutError::MetricNotAvailable)
+    }
+
+    fn screen_is_hdr(&self) -> Result<bool, ReadoutError> {
+        let factory: IDXGIFactory1 = unsafe { CreateDXGIFactory1() }
+            .map_err(|e| ReadoutError::Other(e.message().to_string()))?;
+
+        // Outputs are enumerated through each adapter, until either runs out.
+        for adapter in (0..).map_while(|i| unsafe { factory.EnumAdapters1(i) }.ok()) {
+            for output in (0..).map_while(|i| unsafe { adapter.EnumOutputs(i) }.ok()) {
+                // IDXGIOutput6 is only available from Windows 10 onwards.
+                let Ok(output) = output.cast::<IDXGIOutput6>() else {
+                    return Err(ReadoutError::MetricNotAvailable);
+                };
+
+                let description = unsafe { output.GetDesc1() }
+                    .map_err(|e| ReadoutError::Other(e.message().to_string()))?;
+
+                if description.ColorSpace == DXGI_COLOR_SPACE_RGB_FULL_G2084_NONE_P2020 {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn has_touchscreen(&self) -> Result<bool, ReadoutError> {
+        let digitizer = unsafe { GetSystemMetrics(SM_DIGITIZER) } as u32;
+        Ok(digitizer & (NID_INTEGRATED_TOUCH | NID_EXTERNAL_TOUCH) != 0)
+    }
+
+    #[cfg(feature = "full")]
+    fn display_technology(&self) -> Result<String, ReadoutError> {
+        let wmi_con = wmi_connection_with_namespace("root\\WMI")?;
+        let results: Vec<HashMap<String, Variant>> =
+            wmi_con.raw_query("SELECT InstanceName FROM WmiMonitorID WHERE Active = TRUE")?;
+
+        // The instance name of a monitor is the path of its device under the Enum key,
+        // e.g. "DISPLAY\\DELA0A5\\5&2b3f5f0f&0&UID4353", suffixed with "_0".
+        let instance = match results
+            .first()
+            .and_then(|monitor| monitor.get("InstanceName"))
+        {
+            Some(Variant::String(instance)) => instance,
+            _ => return Err(ReadoutError::MetricNotAvailable),
+        };
+        let device = instance
+            .rsplit_once('_')
+            .map_or(instance.as_str(), |(d, _)| d);
+
+        let edid = RegKey::predef(HKEY_LOCAL_MACHINE)
+            .open_subkey(format!(
+                "SYSTEM\\CurrentControlSet\\Enum\\{device}\\Device Parameters"
+            ))?
+            .get_raw_value("EDID")?;
+
+        crate::shared::edid_display_technology(&edid.bytes)
+            .map(String::from)
+            .ok_or(ReadoutError::MetricNotAvailable)
+    }
+
+    #[cfg(feature = "full")]
+    fn installed_fonts_count(&self) -> Result<usize, ReadoutError> {
+        // Every font is a value of these keys, fonts installed for the current user only
+        // being registered under HKCU.
+        const FONTS: &str = "SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion\\Fonts";
+
+        let system = RegKey::predef(HKEY_LOCAL_MACHINE).open_subkey(FONTS)?;
+        let count = system.enum_values().count()
+            + RegKey::predef(HKEY_CURRENT_USER)
+                .open_subkey(FONTS)
+                .map_or(0, |user| user.enum_values().count());
+
+        Ok(count)
+    }
+
+    fn disk_model(&self, path: &Path) -> Result<String, ReadoutError> {
+        let wide: Vec<u16> = path
+            .as_os_str()
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect();
+
+        let mut volume = [0u16; 261];
+        if !unsafe { GetVolumePathNameW(PCWSTR(wide.as_ptr()), &mut volume) }.as_bool() {
+            return Err(ReadoutError::Other(String::from(
+                "Call to \"GetVolumePathNameW\" failed.",
+            )));
+        }
+
+        // The volume a path lives on, e.g. "C:\\", is opened as "\\.\C:" for its
+        // storage properties, which are those of the disk it's on.
+        let len = volume.iter().position(|&c| c == 0).unwrap_or(volume.len());
+        let volume = String::from_utf16_lossy(&volume[..len]);
+        let device = format!("\\\\.\\{}", volume.trim_end_matches('\\'));
+
+        let handle = WindowsGeneralReadout::open_device(&device, FILE_ACCESS_FLAGS(0))
+            .map_err(|e| ReadoutError::Other(e.message().to_string()))?;
+
+        let query = STORAGE_PROPERTY_QUERY {
+            PropertyId: StorageDeviceProperty,
+            QueryType: PropertyStandardQuery,
+            ..Default::default()
+        };
+        let mut buffer = [0u8; 1024];
+        let mut returned = 0u32;
+
+        let queried = unsafe {
+            DeviceIoControl(
+                handle,
+                IOCTL_STORAGE_QUERY_PROPERTY,
+                &query as *const _ as *const std::ffi::c_void,
+                std::mem::size_of::<STORAGE_PROPERTY_QUERY>() as u32,
+                buffer.as_mut_ptr() as *mut std::ffi::c_void,
+                buffer.len() as u32,
+                &mut returned,
+                std::ptr::null_mut(),
+            )
+        };
+        unsafe { CloseHandle(handle) };
+
+        if !queried.as_bool() {
+            return Err(ReadoutError::Other(String::from(
+                "Call to \"DeviceIoControl\" failed.",
+            )));
+        }
+
+        // The descriptor is followed by the strings it points to, which are null-terminated
+        // and located by their offset from the start of the buffer.
+        let descriptor = unsafe {
+            std::ptr::read_unaligned(buffer.as_ptr() as *const STORAGE_DEVICE_DESCRIPTOR)
+        };
+        let returned = (returned as usize).min(buffer.len());
+        let offset = descriptor.ProductIdOffset as usize;
+        if offset == 0 || offset >= returned {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        let product = &buffer[offset..returned];
+        let len = product
+            .iter()
+            .position(|&c| c == 0)
+            .unwrap_or(product.len());
+        let model = String::from_utf8_lossy(&product[..len]).trim().to_string();
+        if model.is_empty() {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        if descriptor.BusType == BusTypeNvme {
+            Ok(format!("NVMe {model}"))
+        } else if descriptor.BusType == BusTypeSata {
+            Ok(format!("SATA {model}"))
+        } else {
+            Ok(model)
+        }
+    }
+
+    fn nvme_health_percentage(&self, drive: &str) -> Result<u8, ReadoutError> {
+        const LOG_PAGE_SIZE: usize = 512;
+
+        // The query is followed by the description of the protocol specific data that's
+        // requested, and room for that data. The driver overwrites it with a descriptor of
+        // the same layout.
+        #[repr(C)]
+        struct LogPageQuery {
+            property_id: STORAGE_PROPERTY_ID,
+            query_type: STORAGE_QUERY_TYPE,
+            protocol_data: STORAGE_PROTOCOL_SPECIFIC_DATA,
+            log_page: [u8; LOG_PAGE_SIZE],
+        }
+
+        let handle =
+            match WindowsGeneralReadout::open_device(drive, FILE_GENERIC_READ | FILE_GENERIC_WRITE)
+            {
+                Ok(handle) => handle,
+                Err(e) if e.code() == E_ACCESSDENIED => {
+                    return Err(ReadoutError::PermissionDenied(String::from(
+                        "Reading the health of an NVMe drive requires administrator privileges.",
+                    )))
+                }
+                Err(e) => return Err(ReadoutError::Other(e.message().to_string())),
+            };
+
+        let mut query = LogPageQuery {
+            property_id: StorageDeviceProtocolSpecificProperty,
+            query_type: PropertyStandardQuery,
+            protocol_data: STORAGE_PROTOCOL_SPECIFIC_DATA {
+                ProtocolType: ProtocolTypeNvme,
+                DataType: NVMeDataTypeLogPage.0 as u32,
+                // The "SMART / Health Information" log page.
+                ProtocolDataRequestValue: 0x02,
+                ProtocolDataOffset: std::mem::size_of::<STORAGE_PROTOCOL_SPECIFIC_DATA>() as u32,
+                ProtocolDataLength: LOG_PAGE_SIZE as u32,
+                ..Default::default()
+            },
+            log_page: [0; LOG_PAGE_SIZE],
+        };
+        let size = std::mem::size_of::<LogPageQuery>() as u32;
+        let mut returned = 0u32;
+
+        let queried = unsafe {
+            DeviceIoControl(
+                handle,
+                IOCTL_STORAGE_QUERY_PROPERTY,
+                &query as *const _ as *const std::ffi::c_void,
+                size,
+                &mut query as *mut _ as *mut std::ffi::c_void,
+                size,
+                &mut returned,
+                std::ptr::null_mut(),
+            )
+        };
+        unsafe { CloseHandle(handle) };
+
+        // Drives that aren't NVMe ones don't support the protocol specific property.
+        if !queried.as_bool() || (returned as usize) < std::mem::size_of::<LogPageQuery>() {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        // The "Percentage Used" estimate of the drive's life is the sixth byte of the log
+        // page, and may exceed 100 once the drive has outlived its rated endurance.
+        Ok(100 - query.log_page[5].min(100))
+    }
+
+    #[cfg(feature = "full")]
+    fn disk_health(&self, device: &str) -> Result<DiskHealth, ReadoutError> {
+        // Disks are numbered the same way by the storage management provider as they are
+        // in their device path, e.g. "\\.\PhysicalDrive0".
+        let number = device
+            .trim_start_matches("\\\\.\\")
+            .trim_start_matches("PhysicalDrive");
+
+        if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ReadoutError::Other(format!(
+                "{device} is not a physical drive."
+            )));
+        }
+
+        let wmi_con = wmi_connection_with_namespace("root\\Microsoft\\Windows\\Storage")?;
+        let results: Vec<HashMap<String, Variant>> = wmi_con.raw_query(format!(
+            "SELECT HealthStatus FROM MSFT_PhysicalDisk WHERE DeviceId = '{number}'"
+        ))?;
+
+        let disk = results.first().ok_or(ReadoutError::MetricNotAvailable)?;
+        match disk.get("HealthStatus") {
+            Some(Variant::UI2(0)) => Ok(DiskHealth::Ok),
+            Some(Variant::UI2(1)) => Ok(DiskHealth::Warning),
+            Some(Variant::UI2(2)) => Ok(DiskHealth::Failing),
+            Some(Variant::UI2(_)) => Ok(DiskHealth::Unknown),
+            _ => Err(ReadoutError::MetricNotAvailable),
+        }
+    }
+}
+
+impl WindowsGeneralReadout {
+    /// Returns the GPU temperatures published by LibreHardwareMonitor.
+    #[cfg(feature = "full")]
+    fn librehardwaremonitor_gpu_temp() -> Result<Vec<(String, f32)>, ReadoutError> {
+        // Windows doesn't expose GPU sensors on its own, but LibreHardwareMonitor
+        // publishes them through its own WMI namespace while it is running.
+        let wmi_con = wmi_connection_with_namespace("root\\LibreHardwareMonitor")
+            .map_err(|_| ReadoutError::MetricNotAvailable)?;
+
+        let hardware: Vec<HashMap<String, Variant>> = wmi_con
+            .raw_query("SELECT Identifier, Name FROM Hardware WHERE HardwareType LIKE 'Gpu%'")?;
+
+        let sensors: Vec<HashMap<String, Variant>> = wmi_con
+            .raw_query("SELECT Parent, Value FROM Sensor WHERE SensorType = 'Temperature'")?;
+
+        let mut temperatures = vec![];
+        for gpu in &hardware {
+            let (Some(Variant::String(identifier)), Some(Variant::String(name))) =
+                (gpu.get("Identifier"), gpu.get("Name"))
+            else {
+                continue;
+            };
+
+            let temperature = sensors.iter().find_map(|sensor| {
+                match (sensor.get("Parent"), sensor.get("Value")) {
+                    (Some(Variant::String(parent)), Some(Variant::R4(value)))
+                        if parent == identifier =>
+                    {
+                        Some(*value)
+                    }
+                    _ => None,
+                }
+            });
+
+            if let Some(temperature) = temperature {
+                temperatures.push((name.to_owned(), temperature));
+            }
+        }
+
+        if temperatures.is_empty() {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        Ok(temperatures)
+    }
+
+    /// Opens the given device, _e.g._ `\\\\.\\C:` or `\\\\.\\PhysicalDrive0`, to send it
+    /// control codes. The returned handle has to be closed by the caller.
+    fn open_device(device: &str, access: FILE_ACCESS_FLAGS) -> windows::core::Result<HANDLE> {
+        let device: Vec<u16> = device.encode_utf16().chain(std::iter::once(0)).collect();
+
+        unsafe {
+            CreateFileW(
+                PCWSTR(device.as_ptr()),
+                access,
+                FILE_SHARE_READ | FILE_SHARE_WRITE,
+                std::ptr::null(),
+                OPEN_EXISTING,
+                FILE_FLAGS_AND_ATTRIBUTES(0),
+                HANDLE::default(),
+            )
+        }
+    }
+
+    /// Returns the label and space of the drive mounted at the given root, _e.g._ `E:\`.
+    ///
+    /// `None` is returned for drives without any media, such as an empty card reader.
+    fn drive_info(root: String, wide: &[u16]) -> Option<DriveInfo> {
+        let (mut free, mut total) = (0u64, 0u64);
+        let queried = unsafe {
+            GetDiskFreeSpaceExW(
+                PCWSTR(wide.as_ptr()),
+                &mut free,
+                &mut total,
+                std::ptr::null_mut(),
+            )
+        };
+
+        if !queried.as_bool() {
+            return None;
+        }
+
+        let mut label = [0u16; 261];
+        let has_label = unsafe {
+            GetVolumeInformationW(
+                PCWSTR(wide.as_ptr()),
+                &mut label,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                &mut [],
+            )
+        };
+
+        let label = if has_label.as_bool() {
+            let len = label.iter().position(|&c| c == 0).unwrap_or(label.len());
+            Some(String::from_utf16_lossy(&label[..len])).filter(|label| !label.is_empty())
+        } else {
+            None
+        };
+
+        Some(DriveInfo {
+            mount_point: root,
+            label,
+            free,
+            total,
+        })
+    }
+
+    /// Returns the number of seconds elapsed since `Win32_OperatingSystem.LastBootUpTime`.
+    #[cfg(feature = "full")]
+    fn uptime_since_last_boot() -> Result<u64, ReadoutError> {
+        // Querying WMI is slow, and the boot time doesn't change while we're running.
+        static BOOT_TIME: OnceLock<Option<i64>> = OnceLock::new();
+
+        let boot_time = BOOT_TIME
+            .get_or_init(|| WindowsGeneralReadout::last_boot_time().ok())
+            .ok_or(ReadoutError::MetricNotAvailable)?;
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| ReadoutError::Other(e.to_string()))?
+            .as_secs() as i64;
+
+        u64::try_from(now - boot_time).map_err(|_| ReadoutError::MetricNotAvailable)
+    }
+
+    /// Returns `Win32_OperatingSystem.LastBootUpTime` as a Unix timestamp.
+    #[cfg(feature = "full")]
+    fn last_boot_time() -> Result<i64, ReadoutError> {
+        let results: Vec<HashMap<String, Variant>> =
+            wmi_connection()?.raw_query("SELECT LastBootUpTime FROM Win32_OperatingSystem")?;
+
+        match results.first().and_then(|os| os.get("LastBootUpTime")) {
+            Some(Variant::String(datetime)) => WindowsGeneralReadout::parse_cim_datetime(datetime)
+                .ok_or(ReadoutError::MetricNotAvailable),
+            _ => Err(ReadoutError::MetricNotAvailable),
+        }
+    }
+
+    /// Converts a CIM datetime, _e.g._ `20240131093000.500000+060`, to a Unix timestamp.
+    #[cfg(feature = "full")]
+    fn parse_cim_datetime(datetime: &str) -> Option<i64> {
+        let field = |range: std::ops::Range<usize>| datetime.get(range)?.parse::<i64>().ok();
+
+        let (year, month, day) = (field(0..4)?, field(4..6)?, field(6..8)?);
+        let (hour, minute, second) = (field(8..10)?, field(10..12)?, field(12..14)?);
+        // The offset from UTC is given in minutes, along with its sign.
+        let offset = field(21..25)?;
+
+        // Count the days since the Unix epoch, treating January and February as the last
+        // months of the previous year so that leap days fall at the end of it.
+        let (year, month) = if month <= 2 {
+            (year - 1, month + 9)
+        } else {
+            (year, month - 3)
+        };
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let day_of_year = (153 * month + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        let days = era * 146097 + day_of_era - 719468;
+
+        Some(days * 86400 + hour * 3600 + minute * 60 + second - offset * 60)
+    }
+}
+
+pub struct WindowsProductReadout {
+    #[cfg(feature = "full")]
+    manufacturer: Option<String>,
+    #[cfg(feature = "full")]
+    model: Option<String>,
+}
+
+impl ProductReadout for WindowsProductReadout {
+    #[cfg(feature = "full")]
+    fn new() -> Self {
+        let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+        let sys_info = hklm
+            .open_subkey("SYSTEM\\CurrentControlSet\\Control\\SystemInformation")
+            .unwrap();
+
+        WindowsProductReadout {
+            manufacturer: sys_info.get_value("SystemManufacturer").ok(),
+            model: sys_info.get_value("SystemProductName").ok(),
+        }
+    }
+
+    #[cfg(not(feature = "full"))]
+    fn new() -> Self {
+        WindowsProductReadout {}
+    }
+
+    #[cfg(feature = "full")]
+    fn vendor(&self) -> Result<String, ReadoutError> {
+        match &self.manufacturer {
+            Some(v) => Ok(v.clone()),
+            None => Err(ReadoutError::Other(
+                "Trying to get the system manufacturer \
+                from the registry failed"
+                    .to_string(),
+            )),
+        }
+    }
+
+    #[cfg(not(feature = "full"))]
+    fn vendor(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    fn family(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    #[cfg(feature = "full")]
+    fn product(&self) -> Result<String, ReadoutError> {
+        match &self.model {
+            Some(v) => Ok(v.clone()),
+            None => Err(ReadoutError::Other(
+                "Trying to get the system product name \
+                from the registry failed"
+                    .to_string(),
+            )),
+        }
+    }
+
+    #[cfg(not(feature = "full"))]
+    fn product(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    #[cfg(feature = "full")]
+    fn serial_number(&self) -> Result<String, ReadoutError> {
+        let results: Vec<HashMap<String, Variant>> =
+            wmi_connection()?.raw_query("SELECT SerialNumber FROM Win32_BIOS")?;
+
+        results
+            .first()
+            .and_then(|bios| match bios.get("SerialNumber") {
+                Some(Variant::String(serial)) if !serial.trim().is_empty() => {
+                    Some(serial.trim().to_string())
+                }
+                _ => None,
+            })
+            .ok_or(ReadoutError::MetricNotAvailable)
+    }
+
+    #[cfg(feature = "full")]
+    fn form_factor(&self) -> Result<String, ReadoutError> {
+        let results: Vec<HashMap<String, Variant>> =
+            wmi_connection()?.raw_query("SELECT PCSystemType FROM Win32_ComputerSystem")?;
+
+        let form_factor = match results
+            .first()
+            .and_then(|system| system.get("PCSystemType"))
+        {
+            // Desktop, workstation and appliance PC.
+            Some(Variant::UI2(1 | 3 | 6)) => "Desktop",
+            Some(Variant::UI2(2)) => "Laptop",
+            // Enterprise, SOHO and performance servers.
+            Some(Variant::UI2(4 | 5 | 7)) => "Server",
+            _ => return Err(ReadoutError::MetricNotAvailable),
+        };
+
+        Ok(form_factor.to_string())
+    }
+
+    #[cfg(feature = "full")]
+    fn bios_date(&self) -> Result<String, ReadoutError> {
+        let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+        let bios = hklm.open_subkey("HARDWARE\\DESCRIPTION\\System\\BIOS")?;
+
+        bios.get_value::<String, _>("BIOSReleaseDate")
+            .map_err(|_| ReadoutError::MetricNotAvailable)
+    }
+}
+
+impl Refreshable for WindowsProductReadout {
+    fn refresh(&mut self) {
+        *self = WindowsProductReadout::new();
+    }
+}
+
+#[cfg(feature = "full")]
+pub struct WindowsPackageReadout;
+
+#[cfg(feature = "full")]
+impl PackageReadout for WindowsPackageReadout {
+    fn new() -> Self {
+        WindowsPackageReadout {}
+    }
+
+    /// Returns the __number of installed packages__ for the following package managers:
+    /// - cargo
     fn count_pkgs(&self) -> Vec<(PackageManager, usize)> {
         let mut packages = Vec::new();
         if let Some(c) = WindowsPackageReadout::count_cargo() {
             packages.push((PackageManager::Cargo, c));
         }
+        if let Some(c) = WindowsPackageReadout::count_pnpm() {
+            packages.push((PackageManager::Pnpm, c));
+        }
+        if let Some(c) = WindowsPackageReadout::count_bun() {
+            packages.push((PackageManager::Bun, c));
+        }
         if let Some(c) = WindowsPackageReadout::count_scoop() {
             packages.push((PackageManager::Scoop, c));
         }
+        if let Some(c) = WindowsPackageReadout::count_powershellget() {
+            packages.push((PackageManager::PowerShellGet, c));
+        }
         packages
     }
+
+    fn available_updates(&self) -> Result<usize, ReadoutError> {
+        let output = std::process::Command::new("winget")
+            .args([
+                "upgrade",
+                "--accept-source-agreements",
+                "--disable-interactivity",
+            ])
+            .output()?;
+
+        // winget lists the upgrades in a table whose header is underlined with dashes, and
+        // follows it up with a sentence that ends with a period, e.g. "3 upgrades available."
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let count = stdout
+            .lines()
+            .map(str::trim)
+            .skip_while(|line| line.is_empty() || !line.chars().all(|c| c == '-'))
+            .skip(1)
+            .take_while(|line| !line.is_empty() && !line.ends_with('.'))
+            .count();
+
+        Ok(count)
+    }
 }
 
+#[cfg(feature = "full")]
 impl WindowsPackageReadout {
     fn count_cargo() -> Option<usize> {
         crate::shared::count_cargo()
     }
 
+    fn count_pnpm() -> Option<usize> {
+        crate::shared::count_pnpm()
+    }
+
+    fn count_bun() -> Option<usize> {
+        crate::shared::count_bun()
+    }
+
     fn count_scoop() -> Option<usize> {
         let scoop = match std::env::var("SCOOP") {
             Ok(scoop_var) => PathBuf::from(scoop_var),
-            _ => home::home_dir().unwrap().join("scoop"),
+            _ => home::home_dir()?.join("scoop"),
         };
-        match scoop.join("apps").read_dir() {
-            Ok(dir) => Some(dir.count() - 1), // One entry belongs to scoop itself
-            _ => None,
+
+        // A fresh installation may not have created the apps directory yet.
+        let apps = scoop.join("apps").read_dir().ok()?;
+
+        // One entry belongs to scoop itself, an installation without any other app has none
+        // to count.
+        Some(apps.count().saturating_sub(1))
+    }
+
+    /// Counts the PowerShell modules installed system-wide and for the current user.
+    fn count_powershellget() -> Option<usize> {
+        // These are the module management tools themselves.
+        let excluded = ["PackageManagement", "PowerShellGet"];
+
+        let system = std::env::var_os("ProgramFiles")
+            .map(|program_files| PathBuf::from(program_files).join("WindowsPowerShell"));
+        let user = documents_dir().map(|documents| documents.join("WindowsPowerShell"));
+
+        let modules: Vec<PathBuf> = [system, user]
+            .into_iter()
+            .flatten()
+            .filter_map(|dir| dir.join("Modules").read_dir().ok())
+            .flat_map(|entries| entries.map_while(Result::ok))
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .filter(|path| {
+                path.file_name()
+                    .is_none_or(|name| !excluded.iter().any(|e| name == *e))
+            })
+            .collect();
+
+        match modules.len() {
+            0 => None,
+            count => Some(count),
         }
     }
 }
 
+#[cfg(feature = "full")]
 pub struct WindowsNetworkReadout;
+pub struct WindowsProcessReadout;
 
+#[cfg(feature = "full")]
 impl NetworkReadout for WindowsNetworkReadout {
     fn new() -> Self {
         WindowsNetworkReadout
@@ -477,4 +2112,415 @@ impl NetworkReadout for WindowsNetworkReadout {
     fn physical_address(&self, _: Option<&str>) -> Result<String, ReadoutError> {
         Err(ReadoutError::NotImplemented)
     }
+
+    fn broadcast_address(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
+        let (address, prefix_length) = WindowsNetworkReadout::ipv4_address(interface)?;
+        let host_mask = u32::MAX.checked_shr(prefix_length as u32).unwrap_or(0);
+
+        Ok(Ipv4Addr::from(u32::from(address) | host_mask).to_string())
+    }
+
+    fn subnet_mask(&self, interface: Option<&str>) -> Result<String, ReadoutError> {
+        let (_, prefix_length) = WindowsNetworkReadout::ipv4_address(interface)?;
+        let host_mask = u32::MAX.checked_shr(prefix_length as u32).unwrap_or(0);
+
+        Ok(Ipv4Addr::from(!host_mask).to_string())
+    }
+
+    fn interfaces(&self) -> Result<Vec<NetworkInterface>, ReadoutError> {
+        let rows = WindowsNetworkReadout::interface_table()?;
+        let buffer = WindowsNetworkReadout::adapter_addresses(AF_UNSPEC)?;
+
+        let interfaces = WindowsNetworkReadout::adapters(&buffer)
+            .map(|adapter| {
+                let addresses = WindowsNetworkReadout::unicast_addresses(adapter);
+                let row = rows
+                    .iter()
+                    .find(|row| unsafe { row.InterfaceLuid.Value == adapter.Luid.Value });
+
+                let mac = &adapter.PhysicalAddress[..adapter.PhysicalAddressLength as usize];
+
+                NetworkInterface {
+                    name: unsafe { adapter.FriendlyName.to_string() }.unwrap_or_default(),
+                    ipv4: addresses
+                        .iter()
+                        .find(|(address, _)| address.is_ipv4())
+                        .map(|(address, _)| address.to_string()),
+                    ipv6: addresses
+                        .iter()
+                        .find(|(address, _)| address.is_ipv6())
+                        .map(|(address, _)| address.to_string()),
+                    mac: (!mac.is_empty()).then(|| {
+                        mac.iter()
+                            .map(|byte| format!("{byte:02x}"))
+                            .collect::<Vec<_>>()
+                            .join(":")
+                    }),
+                    tx_bytes: row.map(|row| row.OutOctets as usize),
+                    rx_bytes: row.map(|row| row.InOctets as usize),
+                    is_up: adapter.OperStatus == IfOperStatusUp,
+                    mtu: Some(adapter.Mtu),
+                }
+            })
+            .collect();
+
+        Ok(interfaces)
+    }
+
+    fn tcp_connection_count(&self) -> Result<usize, ReadoutError> {
+        Ok(WindowsNetworkReadout::tcp_connection_states()?.len())
+    }
+
+    fn established_tcp_connection_count(&self) -> Result<usize, ReadoutError> {
+        let states = WindowsNetworkReadout::tcp_connection_states()?;
+
+        Ok(states
+            .into_iter()
+            .filter(|&state| state == MIB_TCP_STATE_ESTAB.0 as u32)
+            .count())
+    }
+
+    fn vpn_active(&self) -> Result<bool, ReadoutError> {
+        // The adapters of WireGuard and OpenVPN, as well as those of the built-in VPN
+        // client, e.g. "WAN Miniport (IKEv2)", are told apart by their driver's description.
+        const VPN_DRIVERS: [&str; 4] = ["WireGuard", "TAP-Windows", "OpenVPN", "WAN Miniport"];
+
+        let buffer = WindowsNetworkReadout::adapter_addresses(AF_UNSPEC)?;
+
+        let active = WindowsNetworkReadout::adapters(&buffer).any(|adapter| {
+            let description = unsafe { adapter.Description.to_string() }.unwrap_or_default();
+
+            adapter.OperStatus == IfOperStatusUp
+                && VPN_DRIVERS
+                    .iter()
+                    .any(|driver| description.contains(driver))
+        });
+
+        Ok(active)
+    }
+
+    fn tx_dropped(&self, interface: Option<&str>) -> Result<usize, ReadoutError> {
+        let row = WindowsNetworkReadout::interface_row(interface)?;
+        Ok(row.OutDiscards as usize)
+    }
+
+    fn rx_dropped(&self, interface: Option<&str>) -> Result<usize, ReadoutError> {
+        let row = WindowsNetworkReadout::interface_row(interface)?;
+        Ok(row.InDiscards as usize)
+    }
+
+    fn tx_errors(&self, interface: Option<&str>) -> Result<usize, ReadoutError> {
+        let row = WindowsNetworkReadout::interface_row(interface)?;
+        Ok(row.OutErrors as usize)
+    }
+
+    fn rx_errors(&self, interface: Option<&str>) -> Result<usize, ReadoutError> {
+        let row = WindowsNetworkReadout::interface_row(interface)?;
+        Ok(row.InErrors as usize)
+    }
+}
+
+#[cfg(feature = "full")]
+impl WindowsNetworkReadout {
+    /// Returns the statistics row of every network interface.
+    fn interface_table() -> Result<Vec<MIB_IF_ROW2>, ReadoutError> {
+        let mut table: *mut MIB_IF_TABLE2 = std::ptr::null_mut();
+        if unsafe { GetIfTable2(&mut table) }.is_err() || table.is_null() {
+            return Err(ReadoutError::Other(String::from(
+                "Failed to retrieve the interface table.",
+            )));
+        }
+
+        let rows = unsafe {
+            std::slice::from_raw_parts((*table).Table.as_ptr(), (*table).NumEntries as usize)
+        }
+        .to_vec();
+
+        unsafe { FreeMibTable(table as *const _) };
+
+        Ok(rows)
+    }
+
+    /// Looks up the statistics row of the interface whose alias, i.e. the name shown in
+    /// _Network Connections_, matches `interface`.
+    fn interface_row(interface: Option<&str>) -> Result<MIB_IF_ROW2, ReadoutError> {
+        let Some(interface) = interface else {
+            return Err(ReadoutError::Other(String::from(
+                "Please specify a network interface to query.",
+            )));
+        };
+
+        WindowsNetworkReadout::interface_table()?
+            .into_iter()
+            .find(|row| {
+                let len = row
+                    .Alias
+                    .iter()
+                    .position(|&c| c == 0)
+                    .unwrap_or(row.Alias.len());
+                String::from_utf16_lossy(&row.Alias[..len]) == interface
+            })
+            .ok_or(ReadoutError::MetricNotAvailable)
+    }
+
+    /// Returns the state of every TCP socket, over IPv4 and IPv6.
+    fn tcp_connection_states() -> Result<Vec<u32>, ReadoutError> {
+        let mut states = vec![];
+
+        let buffer = WindowsNetworkReadout::tcp_table(AF_INET)?;
+        let rows = unsafe {
+            let table = &*(buffer.as_ptr() as *const MIB_TCPTABLE_OWNER_PID);
+            std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize)
+        };
+        states.extend(rows.iter().map(|row| row.dwState));
+
+        let buffer = WindowsNetworkReadout::tcp_table(AF_INET6)?;
+        let rows = unsafe {
+            let table = &*(buffer.as_ptr() as *const MIB_TCP6TABLE_OWNER_PID);
+            std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize)
+        };
+        states.extend(rows.iter().map(|row| row.dwState));
+
+        Ok(states)
+    }
+
+    /// Returns the buffer `GetExtendedTcpTable` filled with the table of TCP sockets of the
+    /// given family.
+    fn tcp_table(family: ADDRESS_FAMILY) -> Result<Vec<u64>, ReadoutError> {
+        // As with the adapters' addresses, we're told how much space the table needs
+        // whenever the buffer we provided turns out to be too small.
+        let mut size: u32 = 16 * 1024;
+        loop {
+            let mut buffer: Vec<u64> = vec![0; size as usize / std::mem::size_of::<u64>() + 1];
+            let result = unsafe {
+                GetExtendedTcpTable(
+                    buffer.as_mut_ptr() as *mut std::ffi::c_void,
+                    &mut size,
+                    false,
+                    family.0,
+                    TCP_TABLE_OWNER_PID_ALL,
+                    0,
+                )
+            };
+
+            match WIN32_ERROR(result) {
+                NO_ERROR => return Ok(buffer),
+                ERROR_INSUFFICIENT_BUFFER => continue,
+                _ => {
+                    return Err(ReadoutError::Other(String::from(
+                        "Failed to retrieve the table of TCP connections.",
+                    )))
+                }
+            }
+        }
+    }
+
+    /// Returns the buffer `GetAdaptersAddresses` filled with the linked list of network
+    /// adapters, along with their addresses of the given family.
+    fn adapter_addresses(family: ADDRESS_FAMILY) -> Result<Vec<u64>, ReadoutError> {
+        let flags = GAA_FLAG_SKIP_ANYCAST | GAA_FLAG_SKIP_MULTICAST | GAA_FLAG_SKIP_DNS_SERVER;
+
+        // The size of the list isn't known in advance, we're told how much space it needs
+        // whenever the buffer we provided turns out to be too small.
+        let mut size: u32 = 16 * 1024;
+        loop {
+            let mut buffer: Vec<u64> = vec![0; size as usize / std::mem::size_of::<u64>() + 1];
+            let result = unsafe {
+                GetAdaptersAddresses(
+                    family,
+                    flags,
+                    std::ptr::null_mut(),
+                    buffer.as_mut_ptr() as *mut IP_ADAPTER_ADDRESSES_LH,
+                    &mut size,
+                )
+            };
+
+            match WIN32_ERROR(result) {
+                NO_ERROR => return Ok(buffer),
+                ERROR_BUFFER_OVERFLOW => continue,
+                _ => {
+                    return Err(ReadoutError::Other(String::from(
+                        "Failed to retrieve the addresses of the network adapters.",
+                    )))
+                }
+            }
+        }
+    }
+
+    /// Walks the linked list of adapters held by a buffer returned by
+    /// [WindowsNetworkReadout::adapter_addresses].
+    fn adapters(buffer: &[u64]) -> impl Iterator<Item = &IP_ADAPTER_ADDRESSES_LH> {
+        let first = unsafe { (buffer.as_ptr() as *const IP_ADAPTER_ADDRESSES_LH).as_ref() };
+        std::iter::successors(first, |adapter| unsafe { adapter.Next.as_ref() })
+    }
+
+    /// Returns the unicast addresses assigned to the given adapter, along with the length
+    /// of their network prefix.
+    fn unicast_addresses(adapter: &IP_ADAPTER_ADDRESSES_LH) -> Vec<(IpAddr, u8)> {
+        let first = unsafe { adapter.FirstUnicastAddress.as_ref() };
+
+        std::iter::successors(first, |address| unsafe { address.Next.as_ref() })
+            .filter_map(|address| {
+                let sockaddr = address.Address.lpSockaddr;
+                let family = unsafe { sockaddr.as_ref() }?.sa_family as u32;
+
+                // Addresses are stored in network byte order.
+                let ip = if family == AF_INET.0 {
+                    let sockaddr = unsafe { &*(sockaddr as *const SOCKADDR_IN) };
+                    IpAddr::from(unsafe { sockaddr.sin_addr.S_un.S_addr }.to_ne_bytes())
+                } else if family == AF_INET6.0 {
+                    let sockaddr = unsafe { &*(sockaddr as *const SOCKADDR_IN6) };
+                    IpAddr::from(unsafe { sockaddr.sin6_addr.u.Byte })
+                } else {
+                    return None;
+                };
+
+                Some((ip, address.OnLinkPrefixLength))
+            })
+            .collect()
+    }
+
+    /// Returns the first IPv4 address assigned to the given interface, along with the
+    /// length of its network prefix.
+    fn ipv4_address(interface: Option<&str>) -> Result<(Ipv4Addr, u8), ReadoutError> {
+        let Some(interface) = interface else {
+            return Err(ReadoutError::Other(String::from(
+                "Please specify a network interface to query.",
+            )));
+        };
+
+        let buffer = WindowsNetworkReadout::adapter_addresses(AF_INET)?;
+
+        let address = WindowsNetworkReadout::adapters(&buffer)
+            .filter(|adapter| {
+                unsafe { adapter.FriendlyName.to_string() }.ok().as_deref() == Some(interface)
+            })
+            .flat_map(WindowsNetworkReadout::unicast_addresses)
+            .find_map(|(address, prefix_length)| match address {
+                IpAddr::V4(address) => Some((address, prefix_length)),
+                _ => None,
+            });
+
+        address.ok_or(ReadoutError::MetricNotAvailable)
+    }
+}
+
+impl ProcessReadout for WindowsProcessReadout {
+    fn new() -> Self {
+        WindowsProcessReadout
+    }
+
+    fn top_processes(&self, n: usize, by: SortKey) -> Result<Vec<ProcessInfo>, ReadoutError> {
+        let before = WindowsProcessReadout::snapshot()?;
+        std::thread::sleep(crate::shared::PROCESS_SAMPLING_INTERVAL);
+        let after = WindowsProcessReadout::snapshot()?;
+
+        // CPU times are measured in units of 100 nanoseconds.
+        let elapsed = crate::shared::PROCESS_SAMPLING_INTERVAL.as_nanos() as f32 / 100.0;
+
+        let processes = after
+            .into_iter()
+            .map(|(pid, (name, cpu_time, working_set))| {
+                // Processes spawned in between both samples are measured from their start.
+                let previous = before.get(&pid).map_or(0, |(_, cpu_time, _)| *cpu_time);
+
+                ProcessInfo {
+                    pid,
+                    name,
+                    cpu_usage: cpu_time.saturating_sub(previous) as f32 / elapsed * 100.0,
+                    rss: working_set / 1024,
+                }
+            })
+            .collect();
+
+        Ok(crate::shared::top_processes(processes, n, by))
+    }
+
+    fn process_memory(&self) -> Result<u64, ReadoutError> {
+        WindowsProcessReadout::usage(std::process::id())
+            .map(|(_, working_set)| working_set)
+            .ok_or(ReadoutError::MetricNotAvailable)
+    }
+}
+
+impl WindowsProcessReadout {
+    /// Returns the name, the CPU time spent so far and the working set size, in bytes, of
+    /// every running process, keyed by their PID.
+    ///
+    /// Processes that can't be opened, _e.g._ protected system processes, are reported
+    /// with a CPU time and working set size of zero.
+    fn snapshot() -> Result<HashMap<u32, (String, u64, u64)>, ReadoutError> {
+        let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0) }
+            .map_err(|e| ReadoutError::Other(e.message().to_string()))?;
+
+        let mut processes = HashMap::new();
+        let mut entry = PROCESSENTRY32W {
+            dwSize: std::mem::size_of::<PROCESSENTRY32W>() as u32,
+            ..Default::default()
+        };
+
+        let mut next = unsafe { Process32FirstW(snapshot, &mut entry) }.as_bool();
+        while next {
+            let len = entry
+                .szExeFile
+                .iter()
+                .position(|&c| c == 0)
+                .unwrap_or(entry.szExeFile.len());
+            let name = String::from_utf16_lossy(&entry.szExeFile[..len]);
+            let (cpu_time, working_set) =
+                WindowsProcessReadout::usage(entry.th32ProcessID).unwrap_or((0, 0));
+
+            processes.insert(entry.th32ProcessID, (name, cpu_time, working_set));
+            next = unsafe { Process32NextW(snapshot, &mut entry) }.as_bool();
+        }
+
+        unsafe { CloseHandle(snapshot) };
+
+        Ok(processes)
+    }
+
+    /// Returns the CPU time spent so far by the given process and its working set size.
+    fn usage(pid: u32) -> Option<(u64, u64)> {
+        let process = unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) }.ok()?;
+
+        let mut creation_time = FILETIME::default();
+        let mut exit_time = FILETIME::default();
+        let mut kernel_time = FILETIME::default();
+        let mut user_time = FILETIME::default();
+        let mut counters = PROCESS_MEMORY_COUNTERS::default();
+
+        let times = unsafe {
+            GetProcessTimes(
+                process,
+                &mut creation_time,
+                &mut exit_time,
+                &mut kernel_time,
+                &mut user_time,
+            )
+        }
+        .as_bool();
+
+        let memory = unsafe {
+            K32GetProcessMemoryInfo(
+                process,
+                &mut counters,
+                std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32,
+            )
+        }
+        .as_bool();
+
+        unsafe { CloseHandle(process) };
+
+        if !times || !memory {
+            return None;
+        }
+
+        let to_u64 =
+            |time: FILETIME| (time.dwHighDateTime as u64) << 32 | time.dwLowDateTime as u64;
+
+        Some((
+            to_u64(kernel_time) + to_u64(user_time),
+            counters.WorkingSetSize as u64,
+        ))
+    }
 }
@@ -1,5 +1,5 @@
 //! This module provides a set of functions that detect the name of the window manager the host is
-//! running.
+//! running, and the version of the display server it runs on.
 
 use crate::extra;
 use crate::traits::ReadoutError;
@@ -64,7 +64,10 @@ pub fn detect_xorg_window_manager() -> Result<String, ReadoutError> {
         let window_manager_id_info = String::from_utf8(xprop_id_output.stdout)
             .expect("ERROR: \"xprop -root -notype _NET_SUPPORTING_WM_CHECK\" process stdout was not valid UTF-8");
 
-        let window_manager_id = window_manager_id_info.split(' ').last().unwrap_or_default();
+        let window_manager_id = window_manager_id_info
+            .split(' ')
+            .next_back()
+            .unwrap_or_default();
 
         let xprop_property = Command::new("xprop")
             .args([
@@ -108,7 +111,7 @@ pub fn detect_xorg_window_manager() -> Result<String, ReadoutError> {
     }
 
     if extra::which("wmctrl") {
-        let wmctrl = Command::new("wmctrl")
+        let mut wmctrl = Command::new("wmctrl")
             .arg("-m")
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -117,6 +120,7 @@ pub fn detect_xorg_window_manager() -> Result<String, ReadoutError> {
 
         let wmctrl_out = wmctrl
             .stdout
+            .take()
             .expect("ERROR: failed to open \"wmctrl\" stdout");
 
         let head = Command::new("head")
@@ -130,6 +134,8 @@ pub fn detect_xorg_window_manager() -> Result<String, ReadoutError> {
             .wait_with_output()
             .expect("ERROR: failed to wait for \"head\" process to exit");
 
+        let _ = wmctrl.wait();
+
         let window_manager = String::from_utf8(output.stdout)
             .expect("ERROR: \"wmctrl -m | head -n1\" process stdout was not valid UTF-8");
 
@@ -149,3 +155,122 @@ pub fn detect_xorg_window_manager() -> Result<String, ReadoutError> {
         "\"wmctrl\" must be installed to display your window manager.".to_string(),
     ))
 }
+
+/// Returns the version of the X.Org server, as reported by `Xorg -version`.
+#[cfg(target_os = "linux")]
+pub fn detect_xorg_version() -> Result<String, ReadoutError> {
+    if !extra::which("Xorg") {
+        return Err(ReadoutError::MetricNotAvailable);
+    }
+
+    // Xorg prints its version banner to stderr.
+    let output = Command::new("Xorg")
+        .arg("-version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output()?;
+
+    let banner = String::from_utf8_lossy(&output.stderr);
+    banner
+        .lines()
+        .find_map(|line| line.strip_prefix("X.Org X Server "))
+        .map(|version| format!("X.Org {}", version.trim()))
+        .ok_or(ReadoutError::MetricNotAvailable)
+}
+
+/// Returns the human-readable name of the running Wayland compositor.
+///
+/// The process serving the Wayland display is looked up first, then known compositors among
+/// the running processes, and finally the desktop the session belongs to.
+#[cfg(target_os = "linux")]
+pub fn detect_wayland_compositor() -> Result<String, ReadoutError> {
+    if std::env::var_os("WAYLAND_DISPLAY").is_none() {
+        return Err(ReadoutError::MetricNotAvailable);
+    }
+
+    if let Ok(process) = detect_wayland_window_manager() {
+        return Ok(wayland_compositor_name(&process)
+            .map(String::from)
+            .unwrap_or(process));
+    }
+
+    let running = extra::get_entries(std::path::Path::new("/proc"))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|entry| {
+            entry
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|pid| pid.chars().all(|c| c.is_ascii_digit()))
+        })
+        .find_map(|entry| {
+            let comm = std::fs::read_to_string(entry.join("comm")).ok()?;
+            wayland_compositor_name(&extra::pop_newline(comm))
+        });
+
+    if let Some(name) = running {
+        return Ok(name.to_string());
+    }
+
+    // The variable may list several desktops, e.g. "ubuntu:GNOME".
+    std::env::var("XDG_CURRENT_DESKTOP")
+        .ok()
+        .and_then(|desktops| {
+            desktops.split(':').find_map(|desktop| match desktop {
+                "GNOME" => Some("Mutter"),
+                "KDE" => Some("KWin"),
+                _ => wayland_compositor_name(desktop),
+            })
+        })
+        .map(String::from)
+        .ok_or(ReadoutError::MetricNotAvailable)
+}
+
+/// Maps the name of a compositor's process to the name the compositor goes by.
+#[cfg(target_os = "linux")]
+fn wayland_compositor_name(process: &str) -> Option<&'static str> {
+    let name = match process {
+        "sway" => "Sway",
+        "mutter" | "gnome-shell" => "Mutter",
+        "kwin_wayland" => "KWin",
+        "weston" => "Weston",
+        "Hyprland" => "Hyprland",
+        "river" => "river",
+        "wayfire" => "Wayfire",
+        "labwc" => "labwc",
+        "niri" => "niri",
+        "cosmic-comp" => "COSMIC",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Returns the name and version of the running Wayland compositor, provided it is one of the
+/// compositors known to report their version through `--version`.
+#[cfg(target_os = "linux")]
+pub fn detect_wayland_compositor_version() -> Result<String, ReadoutError> {
+    let compositor = detect_wayland_window_manager()?;
+
+    let name = match compositor.as_str() {
+        "sway" => "Sway",
+        "weston" => "Weston",
+        "gnome-shell" => "GNOME Shell",
+        "kwin_wayland" => "KWin",
+        _ => return Err(ReadoutError::MetricNotAvailable),
+    };
+
+    let output = Command::new(&compositor)
+        .arg("--version")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output()?;
+
+    // e.g. "sway version 1.8.1", "weston 12.0.1", "GNOME Shell 45.1" or "kwin 5.27.8"
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().next_back())
+        .map(|version| format!("{name} {version}"))
+        .ok_or(ReadoutError::MetricNotAvailable)
+}